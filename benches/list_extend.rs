@@ -0,0 +1,66 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Compares building a 100k-item `RayList` with repeated `push` against a
+//! single `extend`.
+//!
+//! Run with `cargo bench --bench list_extend`.
+
+use rayforce::{RayList, Rayforce, Result};
+use std::time::{Duration, Instant};
+
+const ITEMS: i64 = 100_000;
+const ROUNDS: u32 = 10;
+
+fn time<F: FnMut() -> RayList>(mut build: F) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let list = build();
+        best = best.min(start.elapsed());
+        assert_eq!(list.len(), ITEMS as usize);
+    }
+    best
+}
+
+fn main() -> Result<()> {
+    let _rf = Rayforce::new()?;
+
+    let push = time(|| {
+        let mut list = RayList::new();
+        for i in 0..ITEMS {
+            list.push(i);
+        }
+        list
+    });
+    let extend = time(|| {
+        let mut list = RayList::new();
+        list.extend(0..ITEMS);
+        list
+    });
+
+    println!("push   {:>10.2?} for {} items (best of {})", push, ITEMS, ROUNDS);
+    println!("extend {:>10.2?} for {} items (best of {})", extend, ITEMS, ROUNDS);
+    println!("speedup {:.1}x", push.as_secs_f64() / extend.as_secs_f64());
+    Ok(())
+}
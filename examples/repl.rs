@@ -34,6 +34,7 @@
 //! Run with: `cargo run --example repl`
 
 use nu_ansi_term::{Color, Style};
+use rayforce::syntax::{self, Completeness, TokenKind, FUNCTIONS, KEYWORDS};
 use rayforce::{Rayforce, Result};
 use reedline::{
     Completer, Emacs, FileBackedHistory,
@@ -46,56 +47,9 @@ use std::borrow::Cow;
 use std::collections::HashSet;
 
 // ════════════════════════════════════════════════════════════════════════════════
-// Rayforce Keywords and Built-in Functions
+// REPL Commands
 // ════════════════════════════════════════════════════════════════════════════════
 
-const KEYWORDS: &[&str] = &[
-    // Control flow
-    "if", "do", "while", "each", "over", "scan", "peach",
-    // Definitions
-    "def", "let", "set", "get",
-    // Query
-    "select", "update", "insert", "upsert", "delete", "exec", "from", "where", "by",
-    // Joins
-    "inner-join", "left-join", "right-join", "window-join", "asof-join",
-];
-
-const FUNCTIONS: &[&str] = &[
-    // Arithmetic
-    "+", "-", "*", "/", "%", "neg", "abs", "sqrt", "exp", "log", "pow",
-    "sin", "cos", "tan", "asin", "acos", "atan",
-    // Comparison
-    "=", "==", "!=", "<>", "<", ">", "<=", ">=", "~", "like", "match",
-    // Logical
-    "and", "or", "not", "any", "all",
-    // Aggregation
-    "sum", "avg", "min", "max", "count", "first", "last", "med", "dev", "var",
-    "prd", "sums", "prds", "mins", "maxs", "avgs", "devs", "vars",
-    // Sort & Search
-    "asc", "desc", "iasc", "idesc", "xasc", "xdesc", "rank", "bin", "binr",
-    "distinct", "group", "ungroup", "flip", "rotate",
-    // List operations
-    "til", "enlist", "raze", "reverse", "cross", "vs", "sv",
-    "take", "drop", "cut", "sublist", "inter", "union", "except",
-    // String operations
-    "lower", "upper", "trim", "ltrim", "rtrim", "ssr", "ss",
-    // Type operations
-    "type", "null", "count", "cols", "keys", "value", "meta",
-    "string", "symbol", "`int", "`long", "`float", "`date", "`time", "`timestamp",
-    // Table operations
-    "table", "dict", "xkey", "xcol", "xcols", "meta",
-    // I/O
-    "read", "write", "load", "save", "hopen", "hclose", "read-csv",
-    // System
-    "show", "exit", "system", "getenv", "setenv",
-    // Math
-    "floor", "ceil", "round", "signum", "reciprocal",
-    // Date/Time
-    "date", "time", "timestamp", "year", "month", "day", "hour", "minute", "second",
-    // Special
-    "eval", "parse", "value", "quote", "list", "concat", "at", "map", "map-left",
-];
-
 const COMMANDS: &[&str] = &[
     ":?", ":q", ":t", ":v", ":c",
 ];
@@ -152,16 +106,12 @@ impl Prompt for RayPrompt {
 // ════════════════════════════════════════════════════════════════════════════════
 
 struct RayHighlighter {
-    keywords: HashSet<String>,
-    functions: HashSet<String>,
     commands: HashSet<String>,
 }
 
 impl RayHighlighter {
     fn new() -> Self {
         Self {
-            keywords: KEYWORDS.iter().map(|s| s.to_string()).collect(),
-            functions: FUNCTIONS.iter().map(|s| s.to_string()).collect(),
             commands: COMMANDS.iter().map(|s| s.to_string()).collect(),
         }
     }
@@ -170,102 +120,27 @@ impl RayHighlighter {
 impl Highlighter for RayHighlighter {
     fn highlight(&self, line: &str, _cursor: usize) -> StyledText {
         let mut styled = StyledText::new();
-        
-        // Simple tokenization for highlighting
-        let mut chars = line.chars().peekable();
-        let mut current_token = String::new();
-        let mut in_string = false;
-        let mut string_char = '"';
-        
-        while let Some(c) = chars.next() {
-            if in_string {
-                current_token.push(c);
-                if c == string_char {
-                    styled.push((Style::new().fg(Color::Green), current_token.clone()));
-                    current_token.clear();
-                    in_string = false;
-                }
-            } else if c == '"' || c == '\'' {
-                // Flush current token
-                if !current_token.is_empty() {
-                    styled.push(self.style_token(&current_token));
-                    current_token.clear();
-                }
-                in_string = true;
-                string_char = c;
-                current_token.push(c);
-            } else if c == '`' {
-                // Symbol literal
-                if !current_token.is_empty() {
-                    styled.push(self.style_token(&current_token));
-                    current_token.clear();
-                }
-                current_token.push(c);
-                // Read until whitespace or delimiter
-                while let Some(&next) = chars.peek() {
-                    if next.is_whitespace() || "()[]{}".contains(next) {
-                        break;
-                    }
-                    current_token.push(chars.next().unwrap());
-                }
-                styled.push((Style::new().fg(Color::Magenta), current_token.clone()));
-                current_token.clear();
-            } else if c == '(' || c == ')' || c == '[' || c == ']' || c == '{' || c == '}' {
-                // Flush current token
-                if !current_token.is_empty() {
-                    styled.push(self.style_token(&current_token));
-                    current_token.clear();
-                }
-                styled.push((Style::new().fg(Color::Yellow).bold(), c.to_string()));
-            } else if c.is_whitespace() {
-                // Flush current token
-                if !current_token.is_empty() {
-                    styled.push(self.style_token(&current_token));
-                    current_token.clear();
-                }
-                styled.push((Style::new(), c.to_string()));
-            } else if c == ';' {
-                // Comment
-                if !current_token.is_empty() {
-                    styled.push(self.style_token(&current_token));
-                    current_token.clear();
+
+        for token in syntax::tokenize(line) {
+            let text = token.text(line);
+            let style = match token.kind {
+                TokenKind::Keyword | TokenKind::Function | TokenKind::String => {
+                    Style::new().fg(Color::Green)
                 }
-                let mut comment = c.to_string();
-                for remaining in chars.by_ref() {
-                    comment.push(remaining);
+                TokenKind::Symbol => Style::new().fg(Color::Magenta),
+                TokenKind::Number => Style::new().fg(Color::Cyan),
+                TokenKind::Comment => Style::new().fg(Color::DarkGray).italic(),
+                TokenKind::Bracket => Style::new().fg(Color::Yellow).bold(),
+                TokenKind::Whitespace => Style::new(),
+                TokenKind::Ident if self.commands.contains(text) || text.starts_with(':') => {
+                    Style::new().fg(Color::DarkGray)
                 }
-                styled.push((Style::new().fg(Color::DarkGray).italic(), comment));
-            } else {
-                current_token.push(c);
-            }
+                TokenKind::Ident => Style::new().fg(Color::White),
+            };
+            styled.push((style, text.to_string()));
         }
-        
-        // Handle remaining content
-        if in_string {
-            styled.push((Style::new().fg(Color::Green), current_token));
-        } else if !current_token.is_empty() {
-            styled.push(self.style_token(&current_token));
-        }
-        
-        styled
-    }
-}
 
-impl RayHighlighter {
-    fn style_token(&self, token: &str) -> (Style, String) {
-        if self.commands.contains(token) {
-            (Style::new().fg(Color::DarkGray), token.to_string())
-        } else if self.keywords.contains(token) {
-            (Style::new().fg(Color::Green), token.to_string())
-        } else if self.functions.contains(token) {
-            (Style::new().fg(Color::Green), token.to_string())
-        } else if token.parse::<f64>().is_ok() || token.ends_with('i') || token.ends_with('f') {
-            (Style::new().fg(Color::Cyan), token.to_string())
-        } else if token.starts_with(':') {
-            (Style::new().fg(Color::DarkGray), token.to_string())
-        } else {
-            (Style::new().fg(Color::White), token.to_string())
-        }
+        styled
     }
 }
 
@@ -389,35 +264,9 @@ struct RayValidator;
 
 impl Validator for RayValidator {
     fn validate(&self, line: &str) -> ValidationResult {
-        let mut parens = 0i32;
-        let mut brackets = 0i32;
-        let mut braces = 0i32;
-        let mut in_string = false;
-        let mut escape_next = false;
-        
-        for c in line.chars() {
-            if escape_next {
-                escape_next = false;
-                continue;
-            }
-            
-            match c {
-                '\\' if in_string => escape_next = true,
-                '"' => in_string = !in_string,
-                '(' if !in_string => parens += 1,
-                ')' if !in_string => parens -= 1,
-                '[' if !in_string => brackets += 1,
-                ']' if !in_string => brackets -= 1,
-                '{' if !in_string => braces += 1,
-                '}' if !in_string => braces -= 1,
-                _ => {}
-            }
-        }
-        
-        if in_string || parens > 0 || brackets > 0 || braces > 0 {
-            ValidationResult::Incomplete
-        } else {
-            ValidationResult::Complete
+        match syntax::is_complete(line) {
+            Completeness::Complete => ValidationResult::Complete,
+            Completeness::Incomplete { .. } => ValidationResult::Incomplete,
         }
     }
 }
@@ -35,10 +35,22 @@ pub enum RayforceError {
     #[error("Failed to create runtime")]
     RuntimeCreationFailed,
 
+    /// A runtime is already live in this process.
+    #[error("Rayforce runtime is already initialized")]
+    AlreadyInitialized,
+
+    /// Runtime options are invalid or conflict with each other.
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
     /// Failed to evaluate an expression.
     #[error("Evaluation failed: {0}")]
     EvalFailed(String),
 
+    /// Source code could not be parsed.
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
     /// Type mismatch error.
     #[error("Type mismatch: expected {expected}, got {actual}")]
     TypeMismatch {
@@ -73,6 +85,14 @@ pub enum RayforceError {
     #[error("Connection error: {0}")]
     ConnectionError(String),
 
+    /// A remote operation did not complete before its deadline.
+    #[error("Operation timed out")]
+    Timeout,
+
+    /// Error returned by a remote server while evaluating a query.
+    #[error("Remote error: {0}")]
+    RemoteError(String),
+
     /// IO error.
     #[error("IO error: {0}")]
     IoError(String),
@@ -96,6 +116,18 @@ pub enum RayforceError {
     /// Generic C API error.
     #[error("C API error: {0}")]
     CApiError(String),
+
+    /// A statement in a multi-statement script failed.
+    #[error("Statement {index} failed: {source}")]
+    StatementFailed {
+        /// Zero-based index of the failing statement.
+        index: usize,
+        /// Source text of the failing statement.
+        statement: String,
+        /// The underlying error.
+        #[source]
+        source: Box<RayforceError>,
+    },
 }
 
 impl From<std::ffi::NulError> for RayforceError {
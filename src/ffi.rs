@@ -45,6 +45,11 @@ unsafe fn obj_raw_ptr(obj: *mut obj_t) -> *mut i8 {
 ///
 /// This type manages the lifecycle of Rayforce objects, ensuring proper
 /// reference counting and memory management.
+///
+/// `Clone` is shallow: it bumps the reference count and the clone shares
+/// storage with the original. Typed mutators such as `RayVector::set` copy a
+/// shared buffer before writing, but raw writes through the pointer are
+/// visible through every handle. Use [`RayObj::copy`] for an independent copy.
 pub struct RayObj {
     ptr: *mut obj_t,
 }
@@ -68,25 +73,100 @@ impl RayObj {
         unsafe { (*self.ptr).type_ }
     }
 
+    /// Create a deep copy that shares no storage with `self`.
+    pub fn copy(&self) -> RayObj {
+        unsafe { RayObj::from_raw(copy_obj(self.ptr)) }
+    }
+
+    /// Privatize the underlying buffer before an in-place write.
+    ///
+    /// If the object is shared (reference count above 1) it is replaced by a
+    /// private copy, so writes do not leak into other handles.
+    pub(crate) fn make_unique(&mut self) {
+        if self.ref_count() > 1 {
+            unsafe { self.ptr = cow_obj(self.ptr) }
+        }
+    }
+
     /// Check if this is a null/nil object.
     pub fn is_nil(&self) -> bool {
         unsafe { is_null(self.ptr) == 1 }
     }
 
+    /// Check if this is the null atom of its type, e.g. a null i64 or a NaN
+    /// f64.
+    ///
+    /// Vectors, and atoms of types without a null (booleans, bytes,
+    /// characters), are never typed nulls.
+    pub fn is_typed_null(&self) -> bool {
+        if !self.is_atom() {
+            return false;
+        }
+        unsafe {
+            let value = &(*self.ptr).__bindgen_anon_1;
+            match -self.type_code() as u32 {
+                TYPE_I16 => *value.i16_.as_ref() == NULL_I16,
+                TYPE_I32 | TYPE_DATE | TYPE_TIME => *value.i32_.as_ref() == NULL_I32,
+                TYPE_I64 | TYPE_TIMESTAMP => *value.i64_.as_ref() == NULL_I64,
+                TYPE_F64 => value.f64_.as_ref().is_nan(),
+                TYPE_SYMBOL => symbol_to_string(self).map_or(true, |s| s.is_empty()),
+                TYPE_GUID => crate::types::RayGuid::from_ptr(self.clone())
+                    .map(|g| g.to_uuid().is_nil())
+                    .unwrap_or(false),
+                _ => false,
+            }
+        }
+    }
+
     /// Check if this is an error object.
     pub fn is_error(&self) -> bool {
         unsafe { (*self.ptr).type_ == TYPE_ERR as i8 }
     }
 
     /// Check if this is an atom (scalar).
+    ///
+    /// Atoms use the negated type code of the matching vector, e.g. an i64
+    /// atom is `-TYPE_I64` and a GUID atom `-TYPE_GUID`.
     pub fn is_atom(&self) -> bool {
         unsafe { (*self.ptr).type_ < 0 }
     }
 
-    /// Check if this is a vector.
+    /// Check if this is a scalar: a single value of any atom type, including
+    /// symbols, characters and GUIDs. Same as [`is_atom`](Self::is_atom).
+    pub fn is_scalar(&self) -> bool {
+        self.is_atom()
+    }
+
+    /// Check if this holds elements: a vector, list, enum, dict or table.
+    ///
+    /// Functions, errors and the null object are neither scalars nor
+    /// collections.
+    pub fn is_collection(&self) -> bool {
+        let t = unsafe { (*self.ptr).type_ };
+        (t >= TYPE_LIST as i8 && t <= TYPE_C8 as i8)
+            || t == TYPE_ENUM as i8
+            || (t >= TYPE_MAPFILTER as i8 && t <= TYPE_PARTEDLIST as i8)
+            || t == TYPE_TABLE as i8
+            || t == TYPE_DICT as i8
+    }
+
+    /// Check if this is a primitive typed vector (`TYPE_B8` to `TYPE_C8`).
+    ///
+    /// Generic lists and enums are not vectors; see [`is_list`](Self::is_list)
+    /// and [`is_enum`](Self::is_enum).
     pub fn is_vector(&self) -> bool {
         let t = unsafe { (*self.ptr).type_ };
-        t >= 0 && t <= TYPE_ENUM as i8
+        t >= TYPE_B8 as i8 && t <= TYPE_C8 as i8
+    }
+
+    /// Check if this is a generic (heterogeneous) list.
+    pub fn is_list(&self) -> bool {
+        unsafe { (*self.ptr).type_ == TYPE_LIST as i8 }
+    }
+
+    /// Check if this is an enumerated vector.
+    pub fn is_enum(&self) -> bool {
+        unsafe { (*self.ptr).type_ == TYPE_ENUM as i8 }
     }
 
     /// Get the length of the object (for vectors/lists).
@@ -99,11 +179,63 @@ impl RayObj {
         self.len() == 0
     }
 
+    /// Get the number of bytes the object occupies in memory.
+    ///
+    /// Counts the object header and data, plus the footprint of every item
+    /// of a list, dict or table. A sub-object referenced from several places
+    /// is counted once per reference, so the result is an upper bound when
+    /// columns or items are shared. Functions and other opaque objects count
+    /// only their header.
+    pub fn byte_size(&self) -> usize {
+        unsafe { obj_byte_size(self.ptr) }
+    }
+
     /// Get the reference count.
     pub fn ref_count(&self) -> u32 {
         unsafe { rc_obj(self.ptr) }
     }
 
+    /// Panic unless this handle is the only reference to the object.
+    ///
+    /// Intended for tests that check for accidental sharing or leaked references.
+    #[track_caller]
+    pub fn assert_unique(&self) {
+        let rc = self.ref_count();
+        assert!(rc == 1, "expected a unique object, found reference count {}", rc);
+    }
+
+    /// Format with `obj_fmt` and return the raw output bytes.
+    ///
+    /// `mode` 0 is the compact form used by `Display`, 1 the detailed form
+    /// used by `Debug`. Returns an empty vector if formatting fails.
+    pub fn format_bytes(&self, mode: i32) -> Vec<u8> {
+        self.formatted(mode).unwrap_or_default()
+    }
+
+    /// Format in compact mode, replacing invalid UTF-8 with `U+FFFD`.
+    pub fn format_compact(&self) -> String {
+        String::from_utf8_lossy(&self.format_bytes(0)).into_owned()
+    }
+
+    /// Format in detailed mode, replacing invalid UTF-8 with `U+FFFD`.
+    pub fn format_debug(&self) -> String {
+        String::from_utf8_lossy(&self.format_bytes(1)).into_owned()
+    }
+
+    fn formatted(&self, mode: i32) -> Option<Vec<u8>> {
+        unsafe {
+            let obj = obj_fmt(self.ptr, mode as _);
+            if obj.is_null() {
+                return None;
+            }
+            let len = obj_len(obj) as usize;
+            let raw = obj_raw_ptr(obj) as *const u8;
+            let bytes = std::slice::from_raw_parts(raw, len).to_vec();
+            drop_obj(obj);
+            Some(bytes)
+        }
+    }
+
     /// Get the attributes byte.
     pub fn attrs(&self) -> u8 {
         unsafe { (*self.ptr).attrs }
@@ -113,14 +245,249 @@ impl RayObj {
     pub fn set_attrs(&mut self, attrs: u8) {
         unsafe { (*self.ptr).attrs = attrs }
     }
+
+    /// Check structural equality using Rayforce's `~` (match) operator.
+    ///
+    /// Unlike `==`, this compares tables and dicts by their full contents,
+    /// including column names and key order.
+    pub fn deep_equals(&self, other: &RayObj) -> Result<bool> {
+        bool::try_from(call_internal(crate::types::Operation::Match.name(), &[self, other])?)
+    }
+
+    /// Convert to another type with Rayforce's `cast_obj`.
+    ///
+    /// `target` is a type code such as `TYPE_F64 as i8` for an f64 vector or
+    /// `-(TYPE_I64 as i8)` for an i64 atom. Unsupported casts return
+    /// `ConversionError` naming both type codes.
+    pub fn cast(&self, target: i8) -> Result<RayObj> {
+        let source = self.type_code();
+        let failed = |reason: String| {
+            RayforceError::ConversionError(format!(
+                "cannot cast type {} to {}: {}",
+                source, target, reason
+            ))
+        };
+        unsafe {
+            let result = cast_obj(target, self.ptr);
+            if result.is_null() {
+                return Err(failed("cast returned null".into()));
+            }
+            let result = RayObj::from_raw(result);
+            if result.is_error() {
+                return Err(failed(get_error_message(result.ptr)));
+            }
+            if result.type_code() != target {
+                return Err(failed(format!("got type {}", result.type_code())));
+            }
+            Ok(result)
+        }
+    }
+
+    /// Cast to an i64 vector.
+    pub fn to_i64_vector(&self) -> Result<RayObj> {
+        self.cast(TYPE_I64 as i8)
+    }
+
+    /// Cast to an f64 vector.
+    pub fn to_f64_vector(&self) -> Result<RayObj> {
+        self.cast(TYPE_F64 as i8)
+    }
+
+    /// Cast to a symbol vector, e.g. from a list of strings.
+    pub fn to_symbol_vector(&self) -> Result<RayObj> {
+        self.cast(TYPE_SYMBOL as i8)
+    }
+
+    /// Apply a Rayforce function to each element in parallel with `peach`.
+    ///
+    /// Elements are spread across the runtime's worker threads (see
+    /// `RayforceBuilder::worker_threads`); with a single thread this behaves
+    /// like a sequential map. Results come back in element order. `func` runs
+    /// concurrently on several threads, so it must not assign globals or
+    /// depend on evaluation order between elements.
+    pub fn peach(&self, func: &RayObj) -> Result<RayObj> {
+        call_internal("peach", &[func, self])
+    }
+
+    /// Pick the elements at `indices`, in that order, like `(at x indices)`.
+    ///
+    /// With a permutation from `argsort` this reorders a vector or list.
+    /// Out-of-range indices yield nulls.
+    pub fn take(&self, indices: &crate::types::RayVector<i64>) -> Result<RayObj> {
+        call_internal(crate::types::Operation::At.name(), &[self, indices.ptr()])
+    }
+
+    /// Take the first `n` items, like `(take n x)`; a negative `n` takes the
+    /// last `|n|` items.
+    ///
+    /// Works on vectors, lists and tables (by row). Named `take_n` because
+    /// [`take`](Self::take) picks items by index.
+    pub fn take_n(&self, n: i64) -> Result<RayObj> {
+        call_internal(crate::types::Operation::Take.name(), &[&RayObj::from(n), self])
+    }
+
+    /// Drop the first `n` items, like `(drop n x)`; a negative `n` drops the
+    /// last `|n|` items.
+    ///
+    /// Works on vectors, lists and tables (by row).
+    pub fn drop_n(&self, n: i64) -> Result<RayObj> {
+        call_internal(crate::types::Operation::Drop.name(), &[&RayObj::from(n), self])
+    }
+
+    /// Get up to `len` items starting at `start`, like `Vec` slicing.
+    ///
+    /// A negative `start` counts from the end, so `sublist(-3, 2)` is the
+    /// first two of the last three items. The range is clamped to the
+    /// object, so reading past the end returns fewer items instead of
+    /// wrapping around as `take` does. Tables are clamped to their row count.
+    pub fn sublist(&self, start: i64, len: i64) -> Result<RayObj> {
+        let total = if self.type_code() == TYPE_TABLE as i8 {
+            crate::types::RayTable::from_ptr(self.clone())?.len()? as i64
+        } else {
+            self.len()
+        };
+        let start = if start < 0 { total + start } else { start }.clamp(0, total);
+        let len = len.clamp(0, total - start);
+        self.drop_n(start)?.take_n(len)
+    }
+
+    /// Convert into a typed wrapper, failing with `TypeMismatch` if the type
+    /// code does not match.
+    pub fn downcast<T: crate::types::RayType>(self) -> Result<T> {
+        T::from_ptr(self)
+    }
+
+    /// Sort the object into a [`RayValue`](crate::types::RayValue) by its
+    /// runtime type.
+    pub fn classify(&self) -> crate::types::RayValue {
+        crate::types::RayValue::from_obj(self.clone())
+    }
+
+    /// Serialize the object to the Rayforce wire format.
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        unsafe {
+            let bytes = ser_obj(self.ptr);
+            if bytes.is_null() {
+                return Err(RayforceError::ConversionError("serialization failed".into()));
+            }
+            let bytes = RayObj::from_raw(bytes);
+            if bytes.is_error() {
+                return Err(RayforceError::ConversionError(get_error_message(bytes.ptr)));
+            }
+            Ok(bytes.as_slice::<u8>()?.to_vec())
+        }
+    }
+
+    /// Deserialize an object previously produced by [`serialize`](Self::serialize).
+    ///
+    /// Truncated or malformed input returns `ConversionError`.
+    pub fn deserialize(bytes: &[u8]) -> Result<RayObj> {
+        let obj = Self::deserialize_raw(bytes)?;
+        if obj.is_error() {
+            return Err(RayforceError::ConversionError(get_error_message(obj.ptr)));
+        }
+        Ok(obj)
+    }
+
+    /// Deserialize without turning a decoded error object into `Err`, so IPC
+    /// replies can tell a remote error apart from a malformed message.
+    pub(crate) fn deserialize_raw(bytes: &[u8]) -> Result<RayObj> {
+        unsafe {
+            let buf = vector(TYPE_U8 as i8, bytes.len() as i64);
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), obj_raw_ptr(buf) as *mut u8, bytes.len());
+            let buf = RayObj::from_raw(buf);
+
+            let obj = de_obj(buf.ptr);
+            if obj.is_null() {
+                return Err(RayforceError::ConversionError("deserialization failed".into()));
+            }
+            Ok(RayObj::from_raw(obj))
+        }
+    }
+
+    /// View the elements of a vector as a typed slice without copying.
+    ///
+    /// Returns `TypeMismatch` if the object is not a vector of `T`.
+    pub fn as_slice<T: RayPrimitive>(&self) -> Result<&[T]> {
+        if self.type_code() != T::VECTOR_TYPE {
+            return Err(RayforceError::TypeMismatch {
+                expected: T::NAME.into(),
+                actual: format!("type code {}", self.type_code()),
+            });
+        }
+        unsafe {
+            let len = obj_len(self.ptr) as usize;
+            if len == 0 {
+                return Ok(&[]);
+            }
+            let raw = obj_raw_ptr(self.ptr) as *const T;
+            Ok(std::slice::from_raw_parts(raw, len))
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
 }
 
+/// Element types whose vector storage can be viewed directly as a Rust slice.
+///
+/// This trait is sealed; it is implemented only for types whose in-memory
+/// layout matches a Rayforce vector's elements.
+pub trait RayPrimitive: sealed::Sealed + Copy {
+    /// The type code of a vector holding this element type.
+    const VECTOR_TYPE: i8;
+
+    /// Human-readable name used in type mismatch errors.
+    const NAME: &'static str;
+}
+
+macro_rules! impl_ray_primitive {
+    ($ty:ty, $code:expr, $name:expr) => {
+        impl sealed::Sealed for $ty {}
+
+        impl RayPrimitive for $ty {
+            const VECTOR_TYPE: i8 = $code as i8;
+            const NAME: &'static str = $name;
+        }
+    };
+}
+
+impl_ray_primitive!(bool, TYPE_B8, "B8 vector");
+impl_ray_primitive!(u8, TYPE_U8, "U8 vector");
+impl_ray_primitive!(i16, TYPE_I16, "I16 vector");
+impl_ray_primitive!(i32, TYPE_I32, "I32 vector");
+impl_ray_primitive!(i64, TYPE_I64, "I64 vector");
+impl_ray_primitive!(f64, TYPE_F64, "F64 vector");
+
 impl Clone for RayObj {
     fn clone(&self) -> Self {
         unsafe { RayObj::from_raw(clone_obj(self.ptr)) }
     }
 }
 
+/// Equality backed by `cmp_obj`.
+///
+/// Objects of different types are never equal, even if Rayforce would
+/// coerce them (e.g. `1i64` and `1.0f64`).
+impl PartialEq for RayObj {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_code() == other.type_code() && unsafe { cmp_obj(self.ptr, other.ptr) == 0 }
+    }
+}
+
+/// Ordering backed by `cmp_obj`.
+///
+/// Returns `None` when the objects have different types.
+impl PartialOrd for RayObj {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.type_code() != other.type_code() {
+            return None;
+        }
+        Some(unsafe { cmp_obj(self.ptr, other.ptr) }.cmp(&0))
+    }
+}
+
 impl Drop for RayObj {
     fn drop(&mut self) {
         if !self.ptr.is_null() {
@@ -129,40 +496,22 @@ impl Drop for RayObj {
     }
 }
 
+/// Writes the compact `obj_fmt` output. Invalid UTF-8 is replaced with
+/// `U+FFFD`; use [`RayObj::format_bytes`] to get the exact bytes.
 impl fmt::Display for RayObj {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        unsafe {
-            let obj = obj_fmt(self.ptr, 0);
-            if obj.is_null() {
-                write!(f, "null")
-            } else {
-                let len = obj_len(obj) as usize;
-                let raw = obj_raw_ptr(obj) as *const u8;
-                let bytes = std::slice::from_raw_parts(raw, len);
-                let s = String::from_utf8_lossy(bytes);
-                let result = write!(f, "{s}");
-                drop_obj(obj);
-                result
-            }
+        match self.formatted(0) {
+            Some(bytes) => write!(f, "{}", String::from_utf8_lossy(&bytes)),
+            None => write!(f, "null"),
         }
     }
 }
 
 impl fmt::Debug for RayObj {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        unsafe {
-            let obj = obj_fmt(self.ptr, 1);
-            if obj.is_null() {
-                write!(f, "RayObj(null)")
-            } else {
-                let len = obj_len(obj) as usize;
-                let raw = obj_raw_ptr(obj) as *const u8;
-                let bytes = std::slice::from_raw_parts(raw, len);
-                let s = String::from_utf8_lossy(bytes);
-                let result = write!(f, "{s}");
-                drop_obj(obj);
-                result
-            }
+        match self.formatted(1) {
+            Some(bytes) => write!(f, "{}", String::from_utf8_lossy(&bytes)),
+            None => write!(f, "RayObj(null)"),
         }
     }
 }
@@ -257,6 +606,28 @@ impl From<&[i64]> for RayObj {
     }
 }
 
+impl From<&[i16]> for RayObj {
+    fn from(val: &[i16]) -> Self {
+        unsafe {
+            let obj = vector(TYPE_I16 as i8, val.len() as i64);
+            let dst = obj_raw_ptr(obj) as *mut i16;
+            std::ptr::copy_nonoverlapping(val.as_ptr(), dst, val.len());
+            RayObj::from_raw(obj)
+        }
+    }
+}
+
+impl From<&[i32]> for RayObj {
+    fn from(val: &[i32]) -> Self {
+        unsafe {
+            let obj = vector(TYPE_I32 as i8, val.len() as i64);
+            let dst = obj_raw_ptr(obj) as *mut i32;
+            std::ptr::copy_nonoverlapping(val.as_ptr(), dst, val.len());
+            RayObj::from_raw(obj)
+        }
+    }
+}
+
 impl From<&[f64]> for RayObj {
     fn from(val: &[f64]) -> Self {
         unsafe {
@@ -268,12 +639,45 @@ impl From<&[f64]> for RayObj {
     }
 }
 
+impl From<&[bool]> for RayObj {
+    fn from(val: &[bool]) -> Self {
+        unsafe {
+            let obj = vector(TYPE_B8 as i8, val.len() as i64);
+            let dst = obj_raw_ptr(obj) as *mut bool;
+            std::ptr::copy_nonoverlapping(val.as_ptr(), dst, val.len());
+            RayObj::from_raw(obj)
+        }
+    }
+}
+
+impl From<&[u8]> for RayObj {
+    fn from(val: &[u8]) -> Self {
+        unsafe {
+            let obj = vector(TYPE_U8 as i8, val.len() as i64);
+            std::ptr::copy_nonoverlapping(val.as_ptr(), obj_raw_ptr(obj) as *mut u8, val.len());
+            RayObj::from_raw(obj)
+        }
+    }
+}
+
 impl From<Vec<i64>> for RayObj {
     fn from(val: Vec<i64>) -> Self {
         RayObj::from(val.as_slice())
     }
 }
 
+impl From<Vec<i16>> for RayObj {
+    fn from(val: Vec<i16>) -> Self {
+        RayObj::from(val.as_slice())
+    }
+}
+
+impl From<Vec<i32>> for RayObj {
+    fn from(val: Vec<i32>) -> Self {
+        RayObj::from(val.as_slice())
+    }
+}
+
 impl From<Vec<f64>> for RayObj {
     fn from(val: Vec<f64>) -> Self {
         RayObj::from(val.as_slice())
@@ -324,6 +728,48 @@ impl TryFrom<RayObj> for i32 {
     }
 }
 
+impl TryFrom<RayObj> for i16 {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        if obj.type_code() != -(TYPE_I16 as i8) {
+            return Err(RayforceError::TypeMismatch {
+                expected: "I16".into(),
+                actual: format!("type code {}", obj.type_code()),
+            });
+        }
+        unsafe { Ok(*(*obj.ptr).__bindgen_anon_1.i16_.as_ref()) }
+    }
+}
+
+impl TryFrom<RayObj> for u8 {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        if obj.type_code() != -(TYPE_U8 as i8) {
+            return Err(RayforceError::TypeMismatch {
+                expected: "U8".into(),
+                actual: format!("type code {}", obj.type_code()),
+            });
+        }
+        unsafe { Ok(*(*obj.ptr).__bindgen_anon_1.u8_.as_ref()) }
+    }
+}
+
+impl TryFrom<RayObj> for char {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        if obj.type_code() != -(TYPE_C8 as i8) {
+            return Err(RayforceError::TypeMismatch {
+                expected: "C8".into(),
+                actual: format!("type code {}", obj.type_code()),
+            });
+        }
+        unsafe { Ok(*(*obj.ptr).__bindgen_anon_1.c8.as_ref() as u8 as char) }
+    }
+}
+
 impl TryFrom<RayObj> for f64 {
     type Error = RayforceError;
 
@@ -356,6 +802,9 @@ impl TryFrom<RayObj> for String {
     type Error = RayforceError;
 
     fn try_from(obj: RayObj) -> Result<Self> {
+        if obj.type_code() == -(TYPE_SYMBOL as i8) {
+            return symbol_to_string(&obj).ok_or(RayforceError::NullPointer);
+        }
         if obj.type_code() != TYPE_C8 as i8 {
             return Err(RayforceError::TypeMismatch {
                 expected: "String".into(),
@@ -411,6 +860,26 @@ pub fn push_to_list(list: &mut RayObj, item: RayObj) {
     }
 }
 
+/// Append objects to a list, growing it once for the whole batch.
+///
+/// Ownership of each item moves into the list, so no extra references are taken.
+/// A list shared with other handles is copied first.
+pub fn extend_list(list: &mut RayObj, items: Vec<RayObj>) {
+    if items.is_empty() {
+        return;
+    }
+    list.make_unique();
+    unsafe {
+        let start = obj_len(list.ptr);
+        resize_obj(&mut list.ptr as *mut *mut obj_t, start + items.len() as i64);
+        let dst = (obj_raw_ptr(list.ptr) as *mut *mut obj_t).add(start as usize);
+        for (i, item) in items.into_iter().enumerate() {
+            *dst.add(i) = item.ptr;
+            std::mem::forget(item);
+        }
+    }
+}
+
 /// Get item at index from a list/vector.
 pub fn get_at_index(obj: &RayObj, idx: i64) -> Option<RayObj> {
     unsafe {
@@ -431,6 +900,84 @@ pub fn insert_at_index(obj: &mut RayObj, idx: i64, item: RayObj) {
     }
 }
 
+/// Remove and return the last item of a list/vector.
+pub fn pop_from_list(list: &mut RayObj) -> Option<RayObj> {
+    unsafe {
+        if obj_len(list.ptr) == 0 {
+            return None;
+        }
+        let item = pop_obj(&mut list.ptr as *mut *mut obj_t);
+        if item.is_null() {
+            None
+        } else {
+            Some(RayObj::from_raw(item))
+        }
+    }
+}
+
+/// Remove the item at index from a list/vector.
+pub fn remove_at_index(obj: &mut RayObj, idx: i64) {
+    unsafe {
+        remove_idx(&mut obj.ptr as *mut *mut obj_t, idx);
+    }
+}
+
+/// Remove the items at the given sorted, distinct indices from a list/vector.
+pub fn remove_at_indices(obj: &mut RayObj, ids: &[i64]) {
+    if ids.is_empty() {
+        return;
+    }
+    let mut ids = ids.to_vec();
+    unsafe {
+        remove_ids(&mut obj.ptr as *mut *mut obj_t, ids.as_mut_ptr(), ids.len() as i64);
+    }
+}
+
+/// Resize a vector to `len` elements, reallocating if needed.
+///
+/// New elements are uninitialized.
+pub fn resize_vector(obj: &mut RayObj, len: i64) {
+    unsafe {
+        resize_obj(&mut obj.ptr as *mut *mut obj_t, len);
+    }
+}
+
+/// Set the value for a key in a dict, adding the key if it is absent.
+pub fn set_by_key(obj: &mut RayObj, key: &RayObj, value: RayObj) -> Result<()> {
+    unsafe {
+        let cloned = clone_obj(value.ptr);
+        let result = set_obj(&mut obj.ptr as *mut *mut obj_t, key.ptr, cloned);
+        if !result.is_null() && (*result).type_ == TYPE_ERR as i8 {
+            let msg = get_error_message(result);
+            drop_obj(result);
+            return Err(RayforceError::ConversionError(msg));
+        }
+    }
+    Ok(())
+}
+
+/// Find the first index of an object in a list/vector.
+pub fn find_index(obj: &RayObj, needle: &RayObj) -> Option<usize> {
+    unsafe { found_index(obj, find_obj_idx(obj.ptr, needle.ptr)) }
+}
+
+/// Find the first index of a raw 64-bit element (i64 or interned symbol id) in a vector.
+pub fn find_raw_i64(obj: &RayObj, mut value: i64) -> Option<usize> {
+    unsafe {
+        let idx = find_raw(obj.ptr, &mut value as *mut i64 as *mut std::os::raw::c_void);
+        found_index(obj, idx)
+    }
+}
+
+/// Map a C search result to an index; misses are reported as negative or out-of-range values.
+unsafe fn found_index(obj: &RayObj, idx: i64) -> Option<usize> {
+    if idx < 0 || idx >= obj_len(obj.ptr) {
+        None
+    } else {
+        Some(idx as usize)
+    }
+}
+
 /// Create a symbol from a string.
 pub fn new_symbol(s: &str) -> RayObj {
     unsafe {
@@ -439,6 +986,34 @@ pub fn new_symbol(s: &str) -> RayObj {
     }
 }
 
+/// Build a symbol vector by interning each string straight into its slots.
+///
+/// The vector is allocated at its final length and filled with `ins_sym`, so
+/// no intermediate symbol atom is created per element; a single scratch
+/// buffer supplies the NUL-terminated names. `ins_sym` would stop at an
+/// interior NUL, so such strings are interned whole through `symbol`, like
+/// [`new_symbol`].
+pub fn intern_symbols<S: AsRef<str>>(items: &[S]) -> RayObj {
+    unsafe {
+        let mut obj = RayObj::from_raw(vector(TYPE_SYMBOL as i8, items.len() as i64));
+        let mut buf = Vec::new();
+        for (idx, s) in items.iter().enumerate() {
+            let bytes = s.as_ref().as_bytes();
+            if bytes.contains(&0) {
+                let sym = new_symbol(s.as_ref());
+                let id = *(*sym.ptr).__bindgen_anon_1.i64_.as_ref();
+                *(obj_raw_ptr(obj.ptr) as *mut i64).add(idx) = id;
+                continue;
+            }
+            buf.clear();
+            buf.extend_from_slice(bytes);
+            buf.push(0);
+            ins_sym(&mut obj.ptr as *mut *mut obj_t, idx as i64, buf.as_ptr() as *const i8);
+        }
+        obj
+    }
+}
+
 /// Get the string representation of a symbol.
 pub fn symbol_to_string(obj: &RayObj) -> Option<String> {
     if obj.type_code() != -(TYPE_SYMBOL as i8) {
@@ -512,6 +1087,31 @@ pub fn get_internal_function(name: &str) -> Option<RayObj> {
     }
 }
 
+/// Evaluate `(name arg0 arg1 ...)` with a built-in function.
+///
+/// Errors from the runtime are returned as `EvalFailed`.
+pub(crate) fn call_internal(name: &str, args: &[&RayObj]) -> Result<RayObj> {
+    let func = get_internal_function(name)
+        .ok_or_else(|| RayforceError::CApiError(format!("{} not found", name)))?;
+    let mut call = crate::types::RayList::new();
+    call.push(func);
+    for arg in args {
+        call.push((*arg).clone());
+    }
+
+    unsafe {
+        let result = eval_obj(clone_obj(call.ptr().ptr));
+        if result.is_null() {
+            return Err(RayforceError::EvalFailed(format!("{} returned null", name)));
+        }
+        let result = RayObj::from_raw(result);
+        if result.is_error() {
+            return Err(RayforceError::EvalFailed(get_error_message(result.ptr)));
+        }
+        Ok(result)
+    }
+}
+
 /// Get internal function name.
 pub fn get_internal_name(obj: &RayObj) -> Option<String> {
     unsafe {
@@ -542,6 +1142,46 @@ pub fn set_global(name: &str, value: &RayObj) -> Result<RayObj> {
     }
 }
 
+/// Size in bytes of one element of a vector with `type_code`.
+pub(crate) fn element_size(type_code: i8) -> Option<usize> {
+    if type_code <= 0 {
+        return None;
+    }
+    match type_code as u32 {
+        TYPE_B8 | TYPE_U8 | TYPE_C8 => Some(1),
+        TYPE_I16 => Some(2),
+        TYPE_I32 | TYPE_DATE | TYPE_TIME => Some(4),
+        TYPE_I64 | TYPE_SYMBOL | TYPE_TIMESTAMP | TYPE_F64 => Some(8),
+        TYPE_GUID => Some(16),
+        _ => None,
+    }
+}
+
+/// Footprint of an object and everything it references, counting each reference separately.
+unsafe fn obj_byte_size(obj: *mut obj_t) -> usize {
+    if obj.is_null() {
+        return 0;
+    }
+    let type_code = (*obj).type_;
+    if type_code < 0 {
+        return std::mem::size_of::<obj_t>();
+    }
+
+    let header = obj_raw_ptr(obj) as usize - obj as usize;
+    let len = (*obj).__bindgen_anon_1.__bindgen_anon_1.as_ref().len.max(0) as usize;
+    match type_code as u32 {
+        TYPE_LIST | TYPE_TABLE | TYPE_DICT => {
+            let items = obj_raw_ptr(obj) as *const *mut obj_t;
+            let children: usize = (0..len).map(|i| obj_byte_size(*items.add(i))).sum();
+            header + len * std::mem::size_of::<*mut obj_t>() + children
+        }
+        _ => match element_size(type_code) {
+            Some(size) => header + len * size,
+            None => std::mem::size_of::<obj_t>(),
+        },
+    }
+}
+
 /// Get the length of an object (helper for external use)
 pub fn get_obj_len(obj: &RayObj) -> i64 {
     unsafe { obj_len(obj.ptr) }
@@ -25,68 +25,270 @@
 
 use crate::error::{RayforceError, Result};
 use crate::ffi::{self, RayObj};
-use crate::types::RayString;
+use crate::types::{RayList, RayString, RayType};
 use crate::*;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Size of the IPC message header that precedes every serialized payload.
+const HEADER_LEN: usize = 16;
+/// Offset of the message type byte within the header.
+const HEADER_MSGTYPE: usize = 7;
+/// Offset of the little-endian payload size within the header.
+const HEADER_SIZE: usize = 8;
+/// Message type of a server push, as opposed to a sync request or response.
+const MSG_ASYNC: u8 = 0;
+/// Message type of a request that the server answers with a response.
+#[cfg(feature = "async")]
+const MSG_SYNC: u8 = 1;
+/// How often a subscription reader checks whether it has been stopped.
+const SUBSCRIPTION_POLL: Duration = Duration::from_millis(100);
 
 /// A connection to a remote RayforceDB server.
 pub struct Connection {
     handle: RayObj,
-    closed: bool,
+    fd: Option<libc::c_int>,
+    closed: Cell<bool>,
 }
 
 impl Connection {
-    /// Create a new connection from a handle.
-    fn new(handle: RayObj) -> Self {
+    /// Create a new connection from a handle and the socket backing it.
+    fn new(handle: RayObj, fd: Option<libc::c_int>) -> Self {
         Self {
             handle,
-            closed: false,
+            fd,
+            closed: Cell::new(false),
         }
     }
 
     /// Execute a query string on the remote server.
     pub fn execute(&self, query: &str) -> Result<RayObj> {
-        if self.closed {
+        if self.closed.get() {
             return Err(RayforceError::ConnectionError("Connection is closed".into()));
         }
 
         let query_str = RayString::new(query);
+        self.write(query_str.ptr())
+    }
+
+    /// Execute a RayObj query on the remote server.
+    pub fn execute_obj(&self, obj: &RayObj) -> Result<RayObj> {
+        if self.closed.get() {
+            return Err(RayforceError::ConnectionError("Connection is closed".into()));
+        }
+
+        self.write(obj)
+    }
+
+    /// Send a query and wait for the reply.
+    ///
+    /// The runtime reports transport failures and server-side evaluation
+    /// errors alike as error objects, so an error is only attributed to the
+    /// server if the socket is still healthy afterwards. When the socket could
+    /// not be identified at open time, errors are reported as `RemoteError`.
+    fn write(&self, query: &RayObj) -> Result<RayObj> {
         unsafe {
-            let result = ray_write(self.handle.as_ptr(), query_str.ptr().as_ptr());
+            let result = ray_write(self.handle.as_ptr(), query.as_ptr());
             if result.is_null() {
                 return Err(RayforceError::IoError("Write failed".into()));
             }
             if (*result).type_ == TYPE_ERR as i8 {
                 let msg = ffi::get_error_message(result);
                 drop_obj(result);
-                return Err(RayforceError::IoError(msg));
+                if self.fd.is_some_and(socket_failed) {
+                    return Err(RayforceError::IoError(msg));
+                }
+                return Err(RayforceError::RemoteError(msg));
             }
             Ok(RayObj::from_raw(result))
         }
     }
 
-    /// Execute a RayObj query on the remote server.
-    pub fn execute_obj(&self, obj: &RayObj) -> Result<RayObj> {
-        if self.closed {
+    /// Send a query string without waiting for a reply.
+    ///
+    /// The query is written to the negated handle, which the runtime treats
+    /// as an asynchronous message. Nothing comes back for the query itself:
+    /// a remote evaluation error is not reported, only a failure to write it.
+    /// A handle that is not an integer cannot be negated and returns
+    /// `ConnectionError`.
+    pub fn send_async(&self, query: &str) -> Result<()> {
+        if self.closed.get() {
             return Err(RayforceError::ConnectionError("Connection is closed".into()));
         }
 
+        let raw = self.raw_handle();
+        if raw < 0 {
+            return Err(RayforceError::ConnectionError(
+                "Connection handle is not an integer".into(),
+            ));
+        }
+        let handle = RayObj::from(-raw);
+        let query_str = RayString::new(query);
         unsafe {
-            let result = ray_write(self.handle.as_ptr(), obj.as_ptr());
+            let result = ray_write(handle.as_ptr(), query_str.ptr().as_ptr());
             if result.is_null() {
                 return Err(RayforceError::IoError("Write failed".into()));
             }
-            if (*result).type_ == TYPE_ERR as i8 {
-                let msg = ffi::get_error_message(result);
-                drop_obj(result);
-                return Err(RayforceError::IoError(msg));
+            let result = RayObj::from_raw(result);
+            if result.is_error() {
+                return Err(RayforceError::IoError(ffi::get_error_message(result.as_ptr())));
             }
-            Ok(RayObj::from_raw(result))
         }
+        Ok(())
+    }
+
+    /// Execute a query string and convert the result into `T`.
+    ///
+    /// ```rust,no_run
+    /// # use rayforce::ipc::hopen;
+    /// # let conn = hopen("localhost", 5000).unwrap();
+    /// let n: i64 = conn.query("(count t)").unwrap();
+    /// ```
+    pub fn query<T>(&self, query: &str) -> Result<T>
+    where
+        T: TryFrom<RayObj, Error = RayforceError>,
+    {
+        T::try_from(self.execute(query)?)
+    }
+
+    /// Apply a remote function to arguments without building a query string.
+    ///
+    /// The function name is sent as a symbol followed by the arguments as a
+    /// list, so each argument keeps its native type and needs no escaping.
+    pub fn execute_with(&self, func: &str, args: &[RayObj]) -> Result<RayObj> {
+        let mut call = RayList::new();
+        call.push(ffi::new_symbol(func));
+        call.extend(args.iter().cloned());
+        self.execute_obj(call.ptr())
+    }
+
+    /// Execute a query string, failing with `Timeout` if no reply arrives in time.
+    ///
+    /// The deadline is applied as a receive timeout on the underlying socket
+    /// for the duration of the call. When it expires the reply may still be in
+    /// flight, and reading it later would pair it with the wrong query, so the
+    /// connection is closed and subsequent calls return `ConnectionError`.
+    pub fn execute_timeout(&self, query: &str, timeout: Duration) -> Result<RayObj> {
+        if self.closed.get() {
+            return Err(RayforceError::ConnectionError("Connection is closed".into()));
+        }
+
+        let fd = self.socket_fd()?;
+        let previous = set_recv_timeout(fd, socket_timeval(timeout))?;
+        let started = Instant::now();
+        let result = self.execute(query);
+        set_recv_timeout(fd, previous)?;
+
+        match result {
+            Err(RayforceError::IoError(_) | RayforceError::RemoteError(_))
+                if started.elapsed() >= timeout =>
+            {
+                let _ = self.hclose();
+                Err(RayforceError::Timeout)
+            }
+            other => other,
+        }
+    }
+
+    /// Subscribe to updates pushed by the server.
+    ///
+    /// `query` is executed synchronously to register the subscription, then a
+    /// reader thread calls `handler` with each asynchronous message arriving
+    /// on the socket. Frames of any other message type are skipped.
+    ///
+    /// The reader thread must not call into the runtime, so `handler`
+    /// receives each update as its raw framed bytes. Forward them to the
+    /// thread that owns the runtime and decode them there with
+    /// [`RayObj::deserialize`].
+    ///
+    /// The subscription borrows the connection mutably, so it cannot be used
+    /// for other queries or closed while the reader is running. The reader
+    /// stops when the returned [`Subscription`] is unsubscribed or dropped,
+    /// or when the connection fails.
+    pub fn subscribe<F>(&mut self, query: &str, mut handler: F) -> Result<Subscription<'_>>
+    where
+        F: FnMut(Vec<u8>) + Send + 'static,
+    {
+        let fd = self.socket_fd()?;
+        self.execute(query)?;
+        let previous = set_recv_timeout(fd, socket_timeval(SUBSCRIPTION_POLL))?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let reader = {
+            let stop = stop.clone();
+            std::thread::spawn(move || {
+                while let Some(frame) = read_frame(fd, &stop) {
+                    if frame[HEADER_MSGTYPE] == MSG_ASYNC {
+                        handler(frame);
+                    }
+                }
+                let _ = set_recv_timeout(fd, previous);
+            })
+        };
+
+        Ok(Subscription {
+            stop,
+            reader: Some(reader),
+            _conn: PhantomData,
+        })
     }
 
     /// Close the connection.
     pub fn close(&mut self) -> Result<()> {
-        if self.closed {
+        self.hclose()
+    }
+
+    /// Check if the connection is closed.
+    pub fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
+
+    /// Get the integer handle the runtime uses for this connection.
+    ///
+    /// This is the id returned by `hopen`, as accepted by runtime functions
+    /// such as `hclose`. It is not a file descriptor; use
+    /// [`raw_fd`](Self::raw_fd) to poll the socket. Returns -1 if the handle
+    /// is not an integer.
+    pub fn raw_handle(&self) -> i64 {
+        if self.handle.type_code() != -(TYPE_I64 as i8) {
+            return -1;
+        }
+        unsafe { *(*self.handle.as_ptr()).__bindgen_anon_1.i64_.as_ref() }
+    }
+
+    /// Get the file descriptor of the connection's socket.
+    ///
+    /// The runtime does not expose it, so it is found when the connection is
+    /// opened as the one socket to the server's port that `hopen` created.
+    /// Returns `None` if no such socket could be told apart, for example when
+    /// another thread connected to the same port at the same moment.
+    ///
+    /// The descriptor can be registered with `epoll`, `mio` or `poll` to wait
+    /// for readability. Reading or writing it directly bypasses the
+    /// runtime's message framing.
+    pub fn raw_fd(&self) -> Option<std::os::unix::io::RawFd> {
+        self.fd
+    }
+
+    /// Check that the connection is open and its socket has not failed.
+    fn is_healthy(&self) -> bool {
+        !self.closed.get() && !self.fd.is_some_and(socket_failed)
+    }
+
+    /// Get the socket descriptor, for calls that need to configure the socket.
+    fn socket_fd(&self) -> Result<libc::c_int> {
+        self.fd.ok_or_else(|| {
+            RayforceError::ConnectionError("Socket descriptor of the connection is unknown".into())
+        })
+    }
+
+    fn hclose(&self) -> Result<()> {
+        if self.closed.get() {
             return Ok(());
         }
 
@@ -102,20 +304,125 @@ impl Connection {
             }
         }
 
-        self.closed = true;
+        self.closed.set(true);
         Ok(())
     }
+}
 
-    /// Check if the connection is closed.
-    pub fn is_closed(&self) -> bool {
-        self.closed
+/// Convert a deadline to a socket timeval, rounding zero up so it is not read as "no timeout".
+fn socket_timeval(timeout: Duration) -> libc::timeval {
+    let mut tv = libc::timeval {
+        tv_sec: timeout.as_secs() as libc::time_t,
+        tv_usec: timeout.subsec_micros() as libc::suseconds_t,
+    };
+    if tv.tv_sec == 0 && tv.tv_usec == 0 {
+        tv.tv_usec = 1;
+    }
+    tv
+}
+
+/// Set `SO_RCVTIMEO` on a socket, returning the previous value.
+fn set_recv_timeout(fd: libc::c_int, tv: libc::timeval) -> Result<libc::timeval> {
+    let mut previous = libc::timeval { tv_sec: 0, tv_usec: 0 };
+    let mut len = std::mem::size_of::<libc::timeval>() as libc::socklen_t;
+    unsafe {
+        if libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_RCVTIMEO,
+            &mut previous as *mut libc::timeval as *mut libc::c_void,
+            &mut len,
+        ) != 0
+        {
+            return Err(RayforceError::IoError(std::io::Error::last_os_error().to_string()));
+        }
+        if libc::setsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_RCVTIMEO,
+            &tv as *const libc::timeval as *const libc::c_void,
+            std::mem::size_of::<libc::timeval>() as libc::socklen_t,
+        ) != 0
+        {
+            return Err(RayforceError::IoError(std::io::Error::last_os_error().to_string()));
+        }
+    }
+    Ok(previous)
+}
+
+/// Read one framed message (header and payload), or `None` once stopped or on error.
+fn read_frame(fd: libc::c_int, stop: &AtomicBool) -> Option<Vec<u8>> {
+    let mut frame = vec![0u8; HEADER_LEN];
+    read_exact(fd, &mut frame, stop)?;
+    let size = i64::from_le_bytes(frame[HEADER_SIZE..HEADER_LEN].try_into().ok()?);
+    let size = usize::try_from(size).ok()?;
+    frame.resize(HEADER_LEN + size, 0);
+    read_exact(fd, &mut frame[HEADER_LEN..], stop)?;
+    Some(frame)
+}
+
+/// Fill `buf` from the socket, retrying on receive timeouts until `stop` is set.
+fn read_exact(fd: libc::c_int, buf: &mut [u8], stop: &AtomicBool) -> Option<()> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        if stop.load(Ordering::Acquire) {
+            return None;
+        }
+        let rest = &mut buf[filled..];
+        let n = unsafe { libc::recv(fd, rest.as_mut_ptr() as *mut libc::c_void, rest.len(), 0) };
+        match n {
+            0 => return None,
+            n if n > 0 => filled += n as usize,
+            _ => match std::io::Error::last_os_error().kind() {
+                std::io::ErrorKind::WouldBlock
+                | std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::Interrupted => {}
+                _ => return None,
+            },
+        }
+    }
+    Some(())
+}
+
+/// An active subscription started by [`Connection::subscribe`].
+///
+/// Holds the connection's borrow for as long as the reader thread runs, and
+/// stops the reader thread when dropped.
+pub struct Subscription<'conn> {
+    stop: Arc<AtomicBool>,
+    reader: Option<JoinHandle<()>>,
+    _conn: PhantomData<&'conn mut Connection>,
+}
+
+impl Subscription<'_> {
+    /// Stop receiving updates and wait for the reader thread to exit.
+    pub fn unsubscribe(mut self) {
+        self.shutdown();
+    }
+
+    /// Check whether the reader thread is still receiving updates.
+    pub fn is_active(&self) -> bool {
+        self.reader.as_ref().is_some_and(|reader| !reader.is_finished())
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+impl Drop for Subscription<'_> {
+    fn drop(&mut self) {
+        self.shutdown();
     }
 }
 
 impl Drop for Connection {
     fn drop(&mut self) {
-        if !self.closed {
-            let _ = self.close();
+        if !self.closed.get() {
+            let _ = self.hclose();
         }
     }
 }
@@ -137,24 +444,7 @@ pub fn hopen(host: &str, port: u16) -> Result<Connection> {
     let path = format!("{}:{}", host, port);
     let path_str = RayString::new(&path);
     
-    unsafe {
-        let args = [path_str.ptr().as_ptr()];
-        let handle = ray_hopen(args.as_ptr() as *mut *mut obj_t, 1);
-        
-        if handle.is_null() {
-            return Err(RayforceError::ConnectionError(
-                format!("Failed to connect to {}:{}", host, port)
-            ));
-        }
-        
-        if (*handle).type_ == TYPE_ERR as i8 {
-            let msg = ffi::get_error_message(handle);
-            drop_obj(handle);
-            return Err(RayforceError::ConnectionError(msg));
-        }
-        
-        Ok(Connection::new(RayObj::from_raw(handle)))
-    }
+    open(host, port, &[path_str.ptr()])
 }
 
 /// Open a connection with a timeout.
@@ -168,23 +458,388 @@ pub fn hopen_timeout(host: &str, port: u16, timeout_ms: i64) -> Result<Connectio
     let path_str = RayString::new(&path);
     let timeout = RayObj::from(timeout_ms);
     
+    open(host, port, &[path_str.ptr(), &timeout])
+}
+
+/// Call `hopen` with `args` and pick out the socket it connected.
+fn open(host: &str, port: u16, args: &[&RayObj]) -> Result<Connection> {
+    let before = peer_sockets(port);
     unsafe {
-        let args = [path_str.ptr().as_ptr(), timeout.as_ptr()];
-        let handle = ray_hopen(args.as_ptr() as *mut *mut obj_t, 2);
-        
+        let mut args: Vec<*mut obj_t> = args.iter().map(|arg| arg.as_ptr()).collect();
+        let handle = ray_hopen(args.as_mut_ptr(), args.len() as i64);
+
         if handle.is_null() {
             return Err(RayforceError::ConnectionError(
                 format!("Failed to connect to {}:{}", host, port)
             ));
         }
-        
+
         if (*handle).type_ == TYPE_ERR as i8 {
             let msg = ffi::get_error_message(handle);
             drop_obj(handle);
             return Err(RayforceError::ConnectionError(msg));
         }
-        
-        Ok(Connection::new(RayObj::from_raw(handle)))
+
+        let mut opened = peer_sockets(port).into_iter().filter(|fd| !before.contains(fd));
+        let fd = match (opened.next(), opened.next()) {
+            (Some(fd), None) => Some(fd),
+            _ => None,
+        };
+        Ok(Connection::new(RayObj::from_raw(handle), fd))
+    }
+}
+
+/// List the open descriptors of sockets connected to a peer on `port`.
+fn peer_sockets(port: u16) -> Vec<libc::c_int> {
+    let Ok(entries) = std::fs::read_dir("/dev/fd") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .filter(|&fd| peer_port(fd) == Some(port))
+        .collect()
+}
+
+/// Check whether a socket was closed, reset or disconnected by its peer.
+fn socket_failed(fd: libc::c_int) -> bool {
+    if peer_port(fd).is_none() {
+        return true;
+    }
+    let mut error: libc::c_int = 0;
+    let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+    let mut byte = 0u8;
+    unsafe {
+        if libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_ERROR,
+            &mut error as *mut libc::c_int as *mut libc::c_void,
+            &mut len,
+        ) != 0
+            || error != 0
+        {
+            return true;
+        }
+        let n = libc::recv(
+            fd,
+            &mut byte as *mut u8 as *mut libc::c_void,
+            1,
+            libc::MSG_PEEK | libc::MSG_DONTWAIT,
+        );
+        match n {
+            0 => true,
+            n if n > 0 => false,
+            _ => !matches!(
+                std::io::Error::last_os_error().kind(),
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::Interrupted
+            ),
+        }
+    }
+}
+
+/// Get the remote port of a connected TCP socket.
+fn peer_port(fd: libc::c_int) -> Option<u16> {
+    let mut addr: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+    unsafe {
+        if libc::getpeername(fd, &mut addr as *mut _ as *mut libc::sockaddr, &mut len) != 0 {
+            return None;
+        }
+        match i32::from(addr.ss_family) {
+            libc::AF_INET => {
+                let addr = &*(&addr as *const _ as *const libc::sockaddr_in);
+                Some(u16::from_be(addr.sin_port))
+            }
+            libc::AF_INET6 => {
+                let addr = &*(&addr as *const _ as *const libc::sockaddr_in6);
+                Some(u16::from_be(addr.sin6_port))
+            }
+            _ => None,
+        }
+    }
+}
+
+struct PoolState {
+    idle: VecDeque<Connection>,
+    dead: usize,
+}
+
+/// A fixed-size pool of connections to one server.
+///
+/// Connections are handed out round-robin as [`PooledConnection`] guards and
+/// return to the pool when dropped. A connection that hit an IO error, was
+/// closed, or whose socket failed is discarded on return or checkout and
+/// reopened by the next [`get`](Self::get).
+///
+/// Opening and querying connections are runtime calls, so the pool is not
+/// `Send` or `Sync` and lives on the thread that owns the runtime. Checking
+/// out more connections than the pool holds fails instead of blocking, since
+/// no other thread could return one.
+pub struct ConnectionPool {
+    host: String,
+    port: u16,
+    size: usize,
+    state: RefCell<PoolState>,
+}
+
+impl ConnectionPool {
+    /// Open `size` connections to `host:port`.
+    pub fn new(host: &str, port: u16, size: usize) -> Result<Self> {
+        if size == 0 {
+            return Err(RayforceError::ConnectionError("Pool size must be positive".into()));
+        }
+
+        let idle = (0..size)
+            .map(|_| hopen(host, port))
+            .collect::<Result<VecDeque<_>>>()?;
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            size,
+            state: RefCell::new(PoolState { idle, dead: 0 }),
+        })
+    }
+
+    /// Check out a connection, reopening a discarded one if none is idle.
+    ///
+    /// Fails with `ConnectionError` if every connection is checked out.
+    pub fn get(&self) -> Result<PooledConnection<'_>> {
+        if let Some(conn) = self.take_idle() {
+            return Ok(PooledConnection::new(self, conn));
+        }
+
+        let mut state = self.state.borrow_mut();
+        if state.dead == 0 {
+            return Err(RayforceError::ConnectionError(format!(
+                "All {} pooled connections are in use",
+                self.size
+            )));
+        }
+        state.dead -= 1;
+        drop(state);
+
+        match hopen(&self.host, self.port) {
+            Ok(conn) => Ok(PooledConnection::new(self, conn)),
+            Err(e) => {
+                self.state.borrow_mut().dead += 1;
+                Err(e)
+            }
+        }
+    }
+
+    /// Check out an idle connection.
+    ///
+    /// Returns `None` if no open connection is idle. Discarded connections
+    /// are only reopened by [`get`](Self::get), since connecting can block.
+    pub fn try_get(&self) -> Result<Option<PooledConnection<'_>>> {
+        Ok(self.take_idle().map(|conn| PooledConnection::new(self, conn)))
+    }
+
+    /// Get the number of connections managed by the pool.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the number of connections currently available for checkout.
+    pub fn idle(&self) -> usize {
+        self.state.borrow().idle.len()
+    }
+
+    /// Pop the next healthy idle connection, discarding failed ones.
+    fn take_idle(&self) -> Option<Connection> {
+        let mut state = self.state.borrow_mut();
+        while let Some(conn) = state.idle.pop_front() {
+            if conn.is_healthy() {
+                return Some(conn);
+            }
+            state.dead += 1;
+        }
+        None
+    }
+
+    fn release(&self, conn: Connection, broken: bool) {
+        let mut state = self.state.borrow_mut();
+        if broken || !conn.is_healthy() {
+            state.dead += 1;
+            drop(state);
+            drop(conn);
+        } else {
+            state.idle.push_back(conn);
+        }
+    }
+}
+
+/// A connection checked out of a [`ConnectionPool`].
+///
+/// Returns to the pool when dropped.
+pub struct PooledConnection<'a> {
+    pool: &'a ConnectionPool,
+    conn: Option<Connection>,
+    broken: Cell<bool>,
+}
+
+impl<'a> PooledConnection<'a> {
+    fn new(pool: &'a ConnectionPool, conn: Connection) -> Self {
+        Self {
+            pool,
+            conn: Some(conn),
+            broken: Cell::new(false),
+        }
+    }
+
+    /// Execute a query string on the remote server.
+    pub fn execute(&self, query: &str) -> Result<RayObj> {
+        self.track(self.connection().execute(query))
+    }
+
+    /// Execute a RayObj query on the remote server.
+    pub fn execute_obj(&self, obj: &RayObj) -> Result<RayObj> {
+        self.track(self.connection().execute_obj(obj))
+    }
+
+    fn connection(&self) -> &Connection {
+        self.conn.as_ref().expect("pooled connection already released")
+    }
+
+    fn track(&self, result: Result<RayObj>) -> Result<RayObj> {
+        if matches!(result, Err(RayforceError::IoError(_))) || !self.connection().is_healthy() {
+            self.broken.set(true);
+        }
+        result
+    }
+}
+
+impl std::ops::Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection()
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.release(conn, self.broken.get());
+        }
+    }
+}
+
+/// An async wrapper around [`Connection`].
+///
+/// The underlying C handle is not async. Each query is encoded on the
+/// calling thread, and only its bytes are handed to tokio's `spawn_blocking`
+/// pool, which writes them to the socket and reads the reply frame back; the
+/// reply is decoded on the calling thread again. Since encoding and decoding
+/// use the runtime, `AsyncConnection` is not `Send`: drive it from a
+/// current-thread runtime or a `LocalSet` on the thread that owns the
+/// runtime. Queries on the same `AsyncConnection` (and its clones) are
+/// serialized, so open several connections to run queries in parallel.
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncConnection {
+    inner: std::rc::Rc<AsyncInner>,
+}
+
+#[cfg(feature = "async")]
+struct AsyncInner {
+    conn: Connection,
+    fd: libc::c_int,
+    /// Held by a blocking task for a whole request and reply, so replies
+    /// cannot interleave even if an earlier query's future was dropped.
+    io: Arc<std::sync::Mutex<()>>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncConnection {
+    /// Open a connection to a remote RayforceDB server.
+    ///
+    /// Connecting goes through the runtime's `hopen`, so it runs on the
+    /// calling thread and blocks it until the connection is established.
+    pub async fn connect(host: &str, port: u16) -> Result<Self> {
+        let conn = hopen(host, port)?;
+        let fd = conn.socket_fd()?;
+        Ok(Self {
+            inner: std::rc::Rc::new(AsyncInner {
+                conn,
+                fd,
+                io: Arc::new(std::sync::Mutex::new(())),
+            }),
+        })
+    }
+
+    /// Execute a query string on the remote server.
+    pub async fn execute(&self, query: &str) -> Result<RayObj> {
+        if self.inner.conn.is_closed() {
+            return Err(RayforceError::ConnectionError("Connection is closed".into()));
+        }
+
+        let mut request = RayString::new(query).ptr().serialize()?;
+        request[HEADER_MSGTYPE] = MSG_SYNC;
+        let fd = self.inner.fd;
+        let io = self.inner.io.clone();
+        let reply = tokio::task::spawn_blocking(move || {
+            let _io = io
+                .lock()
+                .map_err(|_| RayforceError::ConnectionError("Connection lock poisoned".into()))?;
+            exchange(fd, &request)
+        })
+        .await
+        .map_err(|e| RayforceError::IoError(e.to_string()))??;
+
+        let reply = RayObj::deserialize_raw(&reply)?;
+        if reply.is_error() {
+            return Err(RayforceError::RemoteError(ffi::get_error_message(reply.as_ptr())));
+        }
+        Ok(reply)
+    }
+
+    /// Check if the connection is closed.
+    pub fn is_closed(&self) -> bool {
+        self.inner.conn.is_closed()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for AsyncInner {
+    fn drop(&mut self) {
+        // Wait for a blocking task still reading from the socket, so its
+        // descriptor is not closed and reused underneath it.
+        let _io = self.io.lock();
+        let _ = self.conn.hclose();
+    }
+}
+
+/// Write a framed request and read frames until the reply, skipping pushes.
+///
+/// Only moves bytes, so it can run off the runtime thread.
+#[cfg(feature = "async")]
+fn exchange(fd: libc::c_int, request: &[u8]) -> Result<Vec<u8>> {
+    let mut sent = 0;
+    while sent < request.len() {
+        let rest = &request[sent..];
+        let n = unsafe {
+            libc::send(fd, rest.as_ptr() as *const libc::c_void, rest.len(), libc::MSG_NOSIGNAL)
+        };
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(RayforceError::IoError(err.to_string()));
+        }
+        sent += n as usize;
+    }
+
+    let running = AtomicBool::new(false);
+    loop {
+        let frame = read_frame(fd, &running).ok_or_else(|| {
+            RayforceError::IoError("Connection closed while reading the reply".into())
+        })?;
+        if frame[HEADER_MSGTYPE] != MSG_ASYNC {
+            return Ok(frame);
+        }
     }
 }
 
@@ -200,5 +855,223 @@ mod tests {
         let conn = hopen("localhost", 5000).unwrap();
         assert!(!conn.is_closed());
     }
-}
 
+    #[test]
+    #[ignore]
+    fn test_raw_handle() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("localhost", 5000).unwrap();
+        assert!(conn.raw_handle() > 0);
+        let fd = conn.raw_fd().unwrap();
+        assert_eq!(peer_port(fd), Some(5000));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_remote_error() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("localhost", 5000).unwrap();
+        let err = conn.execute("(+ 1 'a)").unwrap_err();
+        assert!(matches!(err, RayforceError::RemoteError(_)));
+        assert!(!conn.is_closed());
+    }
+
+    // Mock server that completes the handshake and hangs up on the first query.
+    #[test]
+    #[ignore]
+    fn test_dropped_connection_is_io_error() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 256];
+            let n = stream.read(&mut buf).unwrap();
+            stream.write_all(&buf[n.saturating_sub(2)..n.saturating_sub(1)]).unwrap();
+            let _ = stream.read(&mut buf);
+        });
+
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("127.0.0.1", port).unwrap();
+        server.join().unwrap();
+        let err = conn.execute("(+ 1 2)").unwrap_err();
+        assert!(matches!(err, RayforceError::IoError(_)));
+    }
+
+    // Mock server that completes the handshake and then never answers.
+    #[test]
+    #[ignore]
+    fn test_execute_timeout() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 256];
+            let n = stream.read(&mut buf).unwrap();
+            stream.write_all(&buf[n.saturating_sub(2)..n.saturating_sub(1)]).unwrap();
+            let _ = stream.read(&mut buf);
+            std::thread::sleep(Duration::from_secs(2));
+        });
+
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("127.0.0.1", port).unwrap();
+        let err = conn
+            .execute_timeout("(+ 1 2)", Duration::from_millis(100))
+            .unwrap_err();
+        assert!(matches!(err, RayforceError::Timeout));
+        assert!(conn.is_closed());
+        server.join().unwrap();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_execute_with() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("localhost", 5000).unwrap();
+        let result = conn
+            .execute_with("+", &[RayObj::from(1i64), RayObj::from(2i64)])
+            .unwrap();
+        assert_eq!(i64::try_from(result).unwrap(), 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_typed_query() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("localhost", 5000).unwrap();
+
+        let sum: i64 = conn.query("(+ 1 2)").unwrap();
+        assert_eq!(sum, 3);
+
+        let table: crate::RayTable = conn
+            .query("(table [a b] (list [1 2 3] [4 5 6]))")
+            .unwrap();
+        assert_eq!(table.len().unwrap(), 3);
+
+        let err = conn.query::<crate::RayTable>("(+ 1 2)").unwrap_err();
+        assert!(matches!(err, RayforceError::TypeMismatch { .. }));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_send_async_insert() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = hopen("localhost", 5000).unwrap();
+        conn.execute("(set async_log (table [n] (list [0])))").unwrap();
+
+        conn.send_async("(insert 'async_log (list 1))").unwrap();
+        let count: i64 = conn.query("(count async_log)").unwrap();
+        assert_eq!(count, 2);
+    }
+
+    // Requires a server that publishes to subscribers registered by `(sub)`.
+    #[test]
+    #[ignore]
+    fn test_subscribe_receives_updates() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::mpsc;
+
+        let _rf = crate::Rayforce::new().unwrap();
+        let mut conn = hopen("localhost", 5000).unwrap();
+        let received = Arc::new(AtomicUsize::new(0));
+        let (frames, updates) = mpsc::channel();
+
+        let counter = received.clone();
+        let subscription = conn
+            .subscribe("(sub)", move |frame| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = frames.send(frame);
+            })
+            .unwrap();
+        assert!(subscription.is_active());
+
+        let frame = updates.recv_timeout(Duration::from_secs(2)).unwrap();
+        RayObj::deserialize(&frame).unwrap();
+        assert!(received.load(Ordering::SeqCst) > 0);
+        subscription.unsubscribe();
+        assert!(!conn.is_closed());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_pool_reports_exhaustion() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let pool = ConnectionPool::new("localhost", 5000, 2).unwrap();
+
+        let first = pool.get().unwrap();
+        let second = pool.get().unwrap();
+        assert_ne!(first.raw_handle(), second.raw_handle());
+        assert_eq!(pool.idle(), 0);
+        assert!(matches!(pool.get(), Err(RayforceError::ConnectionError(_))));
+        assert!(pool.try_get().unwrap().is_none());
+
+        // Returned connections are handed out again in round-robin order.
+        let (a, b) = (first.raw_handle(), second.raw_handle());
+        drop(first);
+        drop(second);
+        assert_eq!(pool.idle(), 2);
+        assert_eq!(pool.get().unwrap().raw_handle(), a);
+        assert_eq!(pool.get().unwrap().raw_handle(), b);
+
+        let result = pool.get().unwrap().execute("(+ 1 2)").unwrap();
+        assert_eq!(i64::try_from(result).unwrap(), 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_pool_replaces_killed_connection() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let pool = ConnectionPool::new("localhost", 5000, 1).unwrap();
+
+        let conn = pool.get().unwrap();
+        let fd = conn.raw_fd().unwrap();
+        unsafe { libc::shutdown(fd, libc::SHUT_RDWR) };
+        assert!(matches!(conn.execute("(+ 1 2)"), Err(RayforceError::IoError(_))));
+        drop(conn);
+
+        assert_eq!(pool.idle(), 0);
+        assert!(pool.try_get().unwrap().is_none());
+        let conn = pool.get().unwrap();
+        let result = conn.execute("(+ 1 2)").unwrap();
+        assert_eq!(i64::try_from(result).unwrap(), 3);
+        drop(conn);
+
+        // A connection that dies while idle is replaced on checkout.
+        let fd = pool.get().unwrap().raw_fd().unwrap();
+        unsafe { libc::shutdown(fd, libc::SHUT_RDWR) };
+        let result = pool.get().unwrap().execute("(+ 1 2)").unwrap();
+        assert_eq!(i64::try_from(result).unwrap(), 3);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    #[ignore]
+    async fn test_async_concurrent_queries() {
+        let _rf = crate::Rayforce::new().unwrap();
+        let conn = AsyncConnection::connect("localhost", 5000).await.unwrap();
+
+        let local = tokio::task::LocalSet::new();
+        let tasks: Vec<_> = (0..50i64)
+            .map(|i| {
+                let conn = conn.clone();
+                local.spawn_local(async move {
+                    let result = conn.execute(&format!("(+ {} 1)", i)).await.unwrap();
+                    i64::try_from(result).unwrap()
+                })
+            })
+            .collect();
+
+        local
+            .run_until(async {
+                for (i, task) in tasks.into_iter().enumerate() {
+                    assert_eq!(task.await.unwrap(), i as i64 + 1);
+                }
+            })
+            .await;
+    }
+}
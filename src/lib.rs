@@ -62,28 +62,50 @@ pub mod ffi;
 pub mod types;
 pub mod query;
 pub mod ipc;
+pub mod ops;
+pub mod syntax;
 
 pub use error::{RayforceError, Result};
-pub use ffi::RayObj;
+pub use ffi::{RayObj, RayPrimitive};
 pub use types::*;
 // Query types are re-exported from types::table
 // pub use query::*;
-pub use ipc::{Connection, hopen};
+pub use ipc::{Connection, ConnectionPool, PooledConnection, Subscription, hopen};
+#[cfg(feature = "async")]
+pub use ipc::AsyncConnection;
 
 use std::ffi::CString;
 use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
 use std::ptr;
-use std::sync::Once;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 // Include the generated bindings
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
-static INIT: Once = Once::new();
+/// Set while a `Rayforce` instance owns the global runtime.
+static LIVE: AtomicBool = AtomicBool::new(false);
+/// Number of runtimes destroyed so far, for re-initialization errors.
+static DESTROYED: AtomicUsize = AtomicUsize::new(0);
 static mut RUNTIME: *mut runtime_t = ptr::null_mut();
 
+/// Command-line flags behind the typed builder options.
+const FLAG_REPL: &str = "-r";
+const FLAG_PORT: &str = "-p";
+const FLAG_CORES: &str = "-c";
+const FLAG_DATA_DIR: &str = "-d";
+
 /// Builder for creating a Rayforce runtime with custom arguments.
+///
+/// Typed options are passed to the runtime as command-line flags ahead of any
+/// raw arguments added with [`with_arg`](Self::with_arg).
 pub struct RayforceBuilder {
     args: Vec<CString>,
+    repl: Option<bool>,
+    port: Option<u16>,
+    worker_threads: Option<usize>,
+    data_dir: Option<PathBuf>,
+    startup_scripts: Vec<PathBuf>,
 }
 
 impl RayforceBuilder {
@@ -91,17 +113,143 @@ impl RayforceBuilder {
     pub fn new() -> Self {
         Self {
             args: vec![CString::new("rayforce").unwrap()],
+            repl: None,
+            port: None,
+            worker_threads: None,
+            data_dir: None,
+            startup_scripts: Vec::new(),
         }
     }
 
     /// Add a command-line argument.
+    ///
+    /// An escape hatch for flags without a typed option. Passing a flag that
+    /// a typed option also sets makes [`build`](Self::build) fail.
     pub fn with_arg(mut self, arg: &str) -> Self {
         self.args.push(CString::new(arg).unwrap());
         self
     }
 
+    /// Enable or disable the interactive REPL (`-r 1` / `-r 0`).
+    pub fn repl(mut self, enabled: bool) -> Self {
+        self.repl = Some(enabled);
+        self
+    }
+
+    /// Listen for IPC connections on `port` (`-p <port>`).
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Number of worker threads for parallel operations (`-c <n>`).
+    ///
+    /// Must be at least 1.
+    pub fn worker_threads(mut self, n: usize) -> Self {
+        self.worker_threads = Some(n);
+        self
+    }
+
+    /// Directory for loading and saving data (`-d <path>`).
+    ///
+    /// The directory must exist when the runtime is built.
+    pub fn data_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.data_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Evaluate a script file once the runtime is up.
+    ///
+    /// Scripts run after the runtime has been created with all command-line
+    /// arguments, in the order they were added, one statement at a time as
+    /// with [`Rayforce::eval_all`].
+    pub fn with_startup_script(mut self, path: impl AsRef<Path>) -> Self {
+        self.startup_scripts.push(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Build the Rayforce runtime.
-    pub fn build(self) -> Result<Rayforce> {
+    ///
+    /// Fails with [`RayforceError::AlreadyInitialized`] while another
+    /// `Rayforce` instance is alive. Startup scripts are read before the
+    /// runtime is created, so a missing file fails with
+    /// [`RayforceError::IoError`] without touching the runtime; a failing
+    /// statement is reported as [`RayforceError::StatementFailed`] and the
+    /// runtime is dropped.
+    pub fn build(mut self) -> Result<Rayforce> {
+        self.apply_options()?;
+
+        let scripts = self
+            .startup_scripts
+            .iter()
+            .map(|path| {
+                std::fs::read_to_string(path)
+                    .map_err(|e| RayforceError::IoError(format!("{}: {}", path.display(), e)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let ray = self.create()?;
+        for script in &scripts {
+            ray.eval_all(script)?;
+        }
+        Ok(ray)
+    }
+
+    /// Validate the typed options and insert their flags after the program
+    /// name.
+    fn apply_options(&mut self) -> Result<()> {
+        let mut flags: Vec<(&str, String)> = Vec::new();
+        if let Some(enabled) = self.repl {
+            flags.push((FLAG_REPL, if enabled { "1" } else { "0" }.to_string()));
+        }
+        if let Some(port) = self.port {
+            flags.push((FLAG_PORT, port.to_string()));
+        }
+        if let Some(n) = self.worker_threads {
+            if n == 0 {
+                return Err(RayforceError::InvalidConfig(
+                    "worker_threads must be at least 1".to_string(),
+                ));
+            }
+            flags.push((FLAG_CORES, n.to_string()));
+        }
+        if let Some(dir) = &self.data_dir {
+            if !dir.is_dir() {
+                return Err(RayforceError::InvalidConfig(format!(
+                    "data_dir {} is not a directory",
+                    dir.display()
+                )));
+            }
+            let dir = dir.to_str().ok_or(RayforceError::InvalidString)?;
+            flags.push((FLAG_DATA_DIR, dir.to_string()));
+        }
+
+        for (flag, _) in &flags {
+            if self.args[1..].iter().any(|arg| arg.as_bytes() == flag.as_bytes()) {
+                return Err(RayforceError::InvalidConfig(format!(
+                    "{} is set both by a typed option and by with_arg",
+                    flag
+                )));
+            }
+        }
+
+        let mut typed = Vec::with_capacity(flags.len() * 2);
+        for (flag, value) in flags {
+            typed.push(CString::new(flag).unwrap());
+            typed.push(CString::new(value).map_err(|_| RayforceError::InvalidString)?);
+        }
+        self.args.splice(1..1, typed);
+        Ok(())
+    }
+
+    fn create(self) -> Result<Rayforce> {
+        if LIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(RayforceError::AlreadyInitialized);
+        }
+
         unsafe {
             let mut c_args: Vec<*mut c_char> = self
                 .args
@@ -110,12 +258,39 @@ impl RayforceBuilder {
                 .collect();
             c_args.push(ptr::null_mut());
 
+            // A previous `Drop` released the global state with `ray_clean`;
+            // bring it back up before creating the next runtime.
+            let destroyed = DESTROYED.load(Ordering::Acquire);
+            if destroyed > 0 {
+                let rc = ray_init();
+                if rc != 0 {
+                    LIVE.store(false, Ordering::Release);
+                    return Err(RayforceError::CApiError(format!(
+                        "ray_init returned {} when re-initializing after {} destroyed runtime(s)",
+                        rc, destroyed
+                    )));
+                }
+            }
+
             let runtime = runtime_create(c_args.len() as i32 - 1, c_args.as_mut_ptr());
             if !runtime.is_null() {
                 RUNTIME = runtime;
-                Ok(Rayforce { runtime })
+                // The runtime may keep pointers into argv for `runtime_get_arg`,
+                // so the strings must live as long as it does.
+                Ok(Rayforce {
+                    runtime,
+                    _args: self.args,
+                    _argv: c_args,
+                })
             } else {
-                Err(RayforceError::RuntimeCreationFailed)
+                LIVE.store(false, Ordering::Release);
+                match destroyed {
+                    0 => Err(RayforceError::RuntimeCreationFailed),
+                    n => Err(RayforceError::CApiError(format!(
+                        "runtime_create returned null when re-initializing after {} destroyed runtime(s)",
+                        n
+                    ))),
+                }
             }
         }
     }
@@ -130,9 +305,12 @@ impl Default for RayforceBuilder {
 /// The main Rayforce runtime handle.
 ///
 /// This struct manages the lifecycle of the Rayforce database runtime.
-/// Only one runtime can exist at a time.
+/// Only one runtime can exist at a time; creating a second one while the
+/// first is alive returns [`RayforceError::AlreadyInitialized`].
 pub struct Rayforce {
     runtime: *mut runtime_t,
+    _args: Vec<CString>,
+    _argv: Vec<*mut c_char>,
 }
 
 // Safety: The runtime is thread-safe as documented by Rayforce
@@ -144,7 +322,7 @@ impl Rayforce {
     ///
     /// The `-r 0` flag disables the REPL for embedded use.
     pub fn new() -> Result<Self> {
-        RayforceBuilder::new().with_arg("-r").with_arg("0").build()
+        RayforceBuilder::new().repl(false).build()
     }
 
     /// Create a new Rayforce runtime with a builder.
@@ -168,21 +346,114 @@ impl Rayforce {
     }
 
     /// Evaluate a string expression.
+    ///
+    /// Syntax errors are reported as [`RayforceError::ParseError`] and
+    /// failures while evaluating as [`RayforceError::EvalFailed`].
     pub fn eval(&self, code: &str) -> Result<RayObj> {
+        let parsed = self.parse(code)?;
+        self.eval_obj(&parsed)
+    }
+
+    /// Parse a string expression without evaluating it.
+    ///
+    /// The result can be inspected or transformed and later run with
+    /// [`Rayforce::eval_obj`]. Syntax errors are reported as
+    /// [`RayforceError::ParseError`].
+    pub fn parse(&self, code: &str) -> Result<RayObj> {
         let c_str = CString::new(code).map_err(|_| RayforceError::InvalidString)?;
         unsafe {
-            let obj = eval_str(c_str.as_ptr());
+            let obj = parse_str(c_str.as_ptr());
             if obj.is_null() {
-                Err(RayforceError::EvalFailed("Evaluation returned null".into()))
+                Err(RayforceError::ParseError("Parsing returned null".into()))
             } else if (*obj).type_ == TYPE_ERR as i8 {
                 let error_msg = ffi::get_error_message(obj);
-                Err(RayforceError::EvalFailed(error_msg))
+                drop_obj(obj);
+                Err(RayforceError::ParseError(error_msg))
             } else {
                 Ok(RayObj::from_raw(obj))
             }
         }
     }
 
+    /// Evaluate each top-level statement in `code`, in order.
+    ///
+    /// Statements are separated by newlines or by the end of a top-level
+    /// bracketed form. Evaluation stops at the first failure, which is
+    /// reported as [`RayforceError::StatementFailed`] with the statement's
+    /// zero-based index.
+    pub fn eval_all(&self, code: &str) -> Result<Vec<RayObj>> {
+        syntax::split_statements(code)
+            .into_iter()
+            .enumerate()
+            .map(|(index, stmt)| {
+                self.eval(stmt).map_err(|e| RayforceError::StatementFailed {
+                    index,
+                    statement: stmt.to_string(),
+                    source: Box::new(e),
+                })
+            })
+            .collect()
+    }
+
+    /// Evaluate a string expression with error trapping.
+    ///
+    /// Runs the parsed expression through `try_obj`, so errors raised while
+    /// evaluating (type errors, unknown names, raised errors) unwind back
+    /// here and come out as [`RayforceError::EvalFailed`] instead of escaping
+    /// the call. It cannot recover from faults outside the runtime's error
+    /// handling, such as a segfault in C code or memory exhaustion.
+    pub fn try_eval(&self, code: &str) -> Result<RayObj> {
+        let parsed = self.parse(code)?;
+        unsafe {
+            let result = try_obj(clone_obj(parsed.as_ptr()), ptr::null_mut());
+            if result.is_null() {
+                Err(RayforceError::EvalFailed("Evaluation returned null".into()))
+            } else if (*result).type_ == TYPE_ERR as i8 {
+                let error_msg = ffi::get_error_message(result);
+                drop_obj(result);
+                Err(RayforceError::EvalFailed(error_msg))
+            } else {
+                Ok(RayObj::from_raw(result))
+            }
+        }
+    }
+
+    /// Apply a function object to arguments.
+    ///
+    /// Builds the call `(func arg0 arg1 ...)` and evaluates it, so a lambda
+    /// obtained from [`eval`](Self::eval) can be reused without rebuilding
+    /// source text. Built-in unary and binary functions must get exactly one
+    /// and two arguments; lambdas and variadic functions are checked by the
+    /// runtime. Passing something that is not a function returns
+    /// [`RayforceError::TypeMismatch`].
+    pub fn apply(&self, func: &RayObj, args: &[RayObj]) -> Result<RayObj> {
+        let arity = match func.type_code() as u32 {
+            TYPE_UNARY => Some(1),
+            TYPE_BINARY => Some(2),
+            TYPE_LAMBDA | TYPE_VARY => None,
+            _ => {
+                return Err(RayforceError::TypeMismatch {
+                    expected: "function".into(),
+                    actual: types::type_name_for_code(func.type_code()).into(),
+                })
+            }
+        };
+        if let Some(arity) = arity {
+            if args.len() != arity {
+                return Err(RayforceError::EvalFailed(format!(
+                    "function expects {} argument(s), got {}",
+                    arity,
+                    args.len()
+                )));
+            }
+        }
+
+        let mut call = RayList::new();
+        call.push(func.clone());
+        call.extend(args.iter().cloned());
+        self.eval_obj(call.ptr())
+    }
+
     /// Evaluate a RayObj expression.
     pub fn eval_obj(&self, obj: &RayObj) -> Result<RayObj> {
         unsafe {
@@ -203,9 +474,14 @@ impl Rayforce {
 impl Drop for Rayforce {
     fn drop(&mut self) {
         unsafe {
-            runtime_destroy();
+            // `runtime_destroy` tears down the runtime itself; `ray_clean` then
+            // releases the global state so the next `build` starts clean.
             RUNTIME = ptr::null_mut();
+            runtime_destroy();
+            ray_clean();
         }
+        DESTROYED.fetch_add(1, Ordering::AcqRel);
+        LIVE.store(false, Ordering::Release);
     }
 }
 
@@ -0,0 +1,121 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Typed wrappers for Rayforce's built-in functions.
+//!
+//! These build, reshape and aggregate data without going through string
+//! evaluation.
+
+use crate::error::Result;
+use crate::ffi::{self, RayObj};
+use crate::types::{Operation, RayList, RayType, RayVector};
+
+/// Get the integers `0..n`, like `(til n)`. A non-positive `n` gives an empty vector.
+pub fn til(n: i64) -> RayVector<i64> {
+    RayVector::<i64>::from_iter(0..n.max(0))
+}
+
+/// Reverse a vector or list, like `(reverse x)`.
+pub fn reverse(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal("reverse", &[obj])
+}
+
+/// Flatten a list one level, like `(raze x)`.
+///
+/// A list of vectors of one type becomes a single vector of that type.
+pub fn raze(list: &RayList) -> Result<RayObj> {
+    ffi::call_internal("raze", &[list.ptr()])
+}
+
+/// Wrap a value in a one-element list, like `(enlist x)`.
+pub fn enlist(obj: impl Into<RayObj>) -> RayList {
+    let mut list = RayList::new();
+    list.push(obj.into());
+    list
+}
+
+/// Sum the elements, like `(sum x)`. Integer vectors give an `i64` atom,
+/// float vectors an `f64` atom.
+pub fn sum(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Sum.name(), &[obj])
+}
+
+/// Get the mean, like `(avg x)`. Always an `f64` atom.
+pub fn avg(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Avg.name(), &[obj])
+}
+
+/// Get the smallest element, like `(min x)`. An atom of the element type.
+pub fn min(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Min.name(), &[obj])
+}
+
+/// Get the largest element, like `(max x)`. An atom of the element type.
+pub fn max(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Max.name(), &[obj])
+}
+
+/// Count the elements, like `(count x)`. Always an `i64` atom.
+pub fn count(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Count.name(), &[obj])
+}
+
+/// Get the standard deviation, like `(dev x)`. Always an `f64` atom.
+pub fn dev(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Deviation.name(), &[obj])
+}
+
+/// Get the variance, like `(var x)`. Always an `f64` atom.
+pub fn var(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal("var", &[obj])
+}
+
+/// Get the median, like `(med x)`. Always an `f64` atom.
+pub fn med(obj: &RayObj) -> Result<RayObj> {
+    ffi::call_internal(Operation::Median.name(), &[obj])
+}
+
+/// Apply a scan-style built-in to a float vector, keeping the element type.
+fn scan(name: &str, vec: &RayVector<f64>) -> Result<RayVector<f64>> {
+    RayVector::<f64>::from_ptr(ffi::call_internal(name, &[vec.ptr()])?)
+}
+
+/// Get the running total, like `(sums x)`. Same length as the input.
+pub fn running_sum(vec: &RayVector<f64>) -> Result<RayVector<f64>> {
+    scan("sums", vec)
+}
+
+/// Get the running mean, like `(avgs x)`. Same length as the input.
+pub fn running_avg(vec: &RayVector<f64>) -> Result<RayVector<f64>> {
+    scan("avgs", vec)
+}
+
+/// Get the running minimum, like `(mins x)`. Same length as the input.
+pub fn running_min(vec: &RayVector<f64>) -> Result<RayVector<f64>> {
+    scan("mins", vec)
+}
+
+/// Get the running maximum, like `(maxs x)`. Same length as the input.
+pub fn running_max(vec: &RayVector<f64>) -> Result<RayVector<f64>> {
+    scan("maxs", vec)
+}
@@ -27,6 +27,7 @@
 
 pub use crate::types::table::{
     Column,
+    DeleteQuery,
     Expression,
     InsertQuery,
     SelectQuery,
@@ -0,0 +1,447 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Source-level helpers for Rayforce code.
+//!
+//! These work on text alone and do not need a runtime, so editors and other
+//! tools can use them directly.
+
+use std::ops::Range;
+
+/// Whether a piece of input forms complete statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Completeness {
+    /// Every string and bracket is closed.
+    Complete,
+    /// The input ends inside an open string or bracket.
+    Incomplete {
+        /// The unclosed delimiter: `"`, `(`, `[` or `{`.
+        delimiter: char,
+        /// Byte offset of the unclosed delimiter in the input.
+        position: usize,
+    },
+}
+
+/// Check whether `input` is complete, i.e. has no unclosed strings or brackets.
+///
+/// When incomplete, the innermost unclosed delimiter is reported. Delimiters
+/// inside strings, comments (`;` to end of line) and symbol literals (`'sym`
+/// or `` `sym ``) are ignored. Stray closing brackets do not make the input
+/// incomplete; the parser reports them.
+pub fn is_complete(input: &str) -> Completeness {
+    let mut open: Vec<(char, usize)> = Vec::new();
+    let mut string_start = None;
+    let mut escape_next = false;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if string_start.is_some() {
+            match c {
+                _ if escape_next => escape_next = false,
+                '\\' => escape_next = true,
+                '"' => string_start = None,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => string_start = Some(i),
+            ';' => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '\'' | '`' => {
+                while chars
+                    .next_if(|&(_, c)| !c.is_whitespace() && !"()[]{}".contains(c))
+                    .is_some()
+                {}
+            }
+            '(' | '[' | '{' => open.push((c, i)),
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+
+    match (string_start, open.last()) {
+        (Some(position), _) => Completeness::Incomplete {
+            delimiter: '"',
+            position,
+        },
+        (None, Some(&(delimiter, position))) => Completeness::Incomplete {
+            delimiter,
+            position,
+        },
+        (None, None) => Completeness::Complete,
+    }
+}
+
+/// Special forms and query keywords.
+pub const KEYWORDS: &[&str] = &[
+    // Control flow
+    "if", "do", "while", "each", "over", "scan", "peach",
+    // Definitions
+    "def", "let", "set", "get",
+    // Query
+    "select", "update", "insert", "upsert", "delete", "exec", "from", "where", "by",
+    // Joins
+    "inner-join", "left-join", "right-join", "window-join", "asof-join",
+];
+
+/// Built-in function names.
+pub const FUNCTIONS: &[&str] = &[
+    // Arithmetic
+    "+", "-", "*", "/", "%", "neg", "abs", "sqrt", "exp", "log", "pow",
+    "sin", "cos", "tan", "asin", "acos", "atan",
+    // Comparison
+    "=", "==", "!=", "<>", "<", ">", "<=", ">=", "~", "like", "match",
+    // Logical
+    "and", "or", "not", "any", "all",
+    // Aggregation
+    "sum", "avg", "min", "max", "count", "first", "last", "med", "dev", "var",
+    "prd", "sums", "prds", "mins", "maxs", "avgs", "devs", "vars",
+    // Sort & Search
+    "asc", "desc", "iasc", "idesc", "xasc", "xdesc", "rank", "bin", "binr",
+    "distinct", "group", "ungroup", "flip", "rotate",
+    // List operations
+    "til", "enlist", "raze", "reverse", "cross", "vs", "sv",
+    "take", "drop", "cut", "sublist", "inter", "union", "except",
+    // String operations
+    "lower", "upper", "trim", "ltrim", "rtrim", "ssr", "ss",
+    // Type operations
+    "type", "null", "count", "cols", "keys", "value", "meta",
+    "string", "symbol", "`int", "`long", "`float", "`date", "`time", "`timestamp",
+    // Table operations
+    "table", "dict", "xkey", "xcol", "xcols", "meta",
+    // I/O
+    "read", "write", "load", "save", "hopen", "hclose", "read-csv",
+    // System
+    "show", "exit", "system", "getenv", "setenv",
+    // Math
+    "floor", "ceil", "round", "signum", "reciprocal",
+    // Date/Time
+    "date", "time", "timestamp", "year", "month", "day", "hour", "minute", "second",
+    // Special
+    "eval", "parse", "value", "quote", "list", "concat", "at", "map", "map-left",
+];
+
+/// The kind of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A word from [`KEYWORDS`].
+    Keyword,
+    /// A word from [`FUNCTIONS`].
+    Function,
+    /// A symbol literal: `'sym` or `` `sym ``.
+    Symbol,
+    /// A string literal, including its quotes. May be unterminated.
+    String,
+    /// A numeric or temporal literal such as `42`, `-1.5` or `09:30:00`.
+    Number,
+    /// A `;` comment, up to but not including the end of the line.
+    Comment,
+    /// One of `()[]{}`.
+    Bracket,
+    /// A run of whitespace.
+    Whitespace,
+    /// Any other word, e.g. a variable name.
+    Ident,
+}
+
+/// A lexical token: its kind and byte range in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// What the token is.
+    pub kind: TokenKind,
+    /// Byte range of the token in the input.
+    pub span: Range<usize>,
+}
+
+impl Token {
+    /// The text of this token within `input`.
+    pub fn text<'a>(&self, input: &'a str) -> &'a str {
+        &input[self.span.clone()]
+    }
+}
+
+/// Split `input` into tokens for highlighting and similar tooling.
+///
+/// Every byte of the input belongs to exactly one token, so the spans cover
+/// `0..input.len()` in order. Words end at whitespace, brackets, quotes and
+/// comments; operators such as `>` are not split out of a word.
+pub fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let kind = match c {
+            '"' => {
+                let mut escape_next = false;
+                for (_, c) in chars.by_ref() {
+                    match c {
+                        _ if escape_next => escape_next = false,
+                        '\\' => escape_next = true,
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+                TokenKind::String
+            }
+            ';' => {
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+                TokenKind::Comment
+            }
+            '\'' | '`' => {
+                while chars
+                    .next_if(|&(_, c)| !c.is_whitespace() && !"()[]{}".contains(c))
+                    .is_some()
+                {}
+                TokenKind::Symbol
+            }
+            '(' | ')' | '[' | ']' | '{' | '}' => TokenKind::Bracket,
+            c if c.is_whitespace() => {
+                while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+                TokenKind::Whitespace
+            }
+            _ => {
+                while chars
+                    .next_if(|&(_, c)| !c.is_whitespace() && !"()[]{}\";".contains(c))
+                    .is_some()
+                {}
+                let end = chars.peek().map_or(input.len(), |&(i, _)| i);
+                classify_word(&input[start..end])
+            }
+        };
+        let end = chars.peek().map_or(input.len(), |&(i, _)| i);
+        tokens.push(Token {
+            kind,
+            span: start..end,
+        });
+    }
+
+    tokens
+}
+
+fn classify_word(word: &str) -> TokenKind {
+    if KEYWORDS.contains(&word) {
+        return TokenKind::Keyword;
+    }
+    if FUNCTIONS.contains(&word) {
+        return TokenKind::Function;
+    }
+    let digits = word.strip_prefix('-').unwrap_or(word);
+    let digits = digits.strip_prefix('.').unwrap_or(digits);
+    if digits.starts_with(|c: char| c.is_ascii_digit()) {
+        TokenKind::Number
+    } else {
+        TokenKind::Ident
+    }
+}
+
+/// Split source code into top-level statements.
+///
+/// A statement ends at a newline outside any brackets, or where a top-level
+/// bracketed form closes. Brackets inside strings, comments and symbol
+/// literals are ignored. Statements containing only whitespace or comments are
+/// skipped.
+pub(crate) fn split_statements(code: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escape_next = false;
+    let mut has_code = false;
+    let mut start = 0;
+    let mut chars = code.char_indices().peekable();
+
+    let mut push = |start: usize, end: usize, has_code: bool| {
+        let stmt = code[start..end].trim();
+        if has_code && !stmt.is_empty() {
+            statements.push(stmt);
+        }
+    };
+
+    while let Some((i, c)) = chars.next() {
+        if in_string {
+            match c {
+                _ if escape_next => escape_next = false,
+                '\\' => escape_next = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                has_code = true;
+            }
+            ';' => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '\'' | '`' => {
+                has_code = true;
+                while chars
+                    .next_if(|&(_, c)| !c.is_whitespace() && !"()[]{}".contains(c))
+                    .is_some()
+                {}
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                has_code = true;
+            }
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    push(start, i + 1, true);
+                    start = i + 1;
+                    has_code = false;
+                }
+            }
+            '\n' if depth <= 0 => {
+                push(start, i, has_code);
+                start = i + 1;
+                has_code = false;
+                depth = 0;
+            }
+            c if !c.is_whitespace() => has_code = true,
+            _ => {}
+        }
+    }
+    push(start, code.len(), has_code);
+
+    statements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_input_is_complete() {
+        assert_eq!(is_complete("(+ 1 [2 3] {a: 1})"), Completeness::Complete);
+        assert_eq!(is_complete(""), Completeness::Complete);
+    }
+
+    #[test]
+    fn test_open_string_is_incomplete() {
+        assert_eq!(
+            is_complete("(print \"hello)"),
+            Completeness::Incomplete {
+                delimiter: '"',
+                position: 7
+            }
+        );
+        assert_eq!(is_complete("(print \"a \\\" b\")"), Completeness::Complete);
+    }
+
+    #[test]
+    fn test_open_paren_is_incomplete() {
+        assert_eq!(
+            is_complete("(select {from: t\n  where: (> x 1)"),
+            Completeness::Incomplete {
+                delimiter: '{',
+                position: 8
+            }
+        );
+        assert_eq!(
+            is_complete("(+ 1 ; )\n"),
+            Completeness::Incomplete {
+                delimiter: '(',
+                position: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_symbol_literal_with_bracket_like_characters() {
+        assert_eq!(is_complete("(set s 'a<b>)"), Completeness::Complete);
+        assert_eq!(is_complete("['AAPL 'MSFT]"), Completeness::Complete);
+        assert_eq!(is_complete("(set s `x\"y)"), Completeness::Complete);
+    }
+
+    #[test]
+    fn test_split_statements() {
+        let code = "; header\n(set a 1) (set b 2)\n(+ a\n b)\n\"x;y\"\n";
+        assert_eq!(
+            split_statements(code),
+            vec!["(set a 1)", "(set b 2)", "(+ a\n b)", "\"x;y\""]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_query_line() {
+        use TokenKind::*;
+
+        let line = "select price by sym from t where size>0";
+        let tokens = tokenize(line);
+        let kinds: Vec<_> = tokens.iter().map(|t| (t.kind, t.text(line))).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (Keyword, "select"),
+                (Whitespace, " "),
+                (Ident, "price"),
+                (Whitespace, " "),
+                (Keyword, "by"),
+                (Whitespace, " "),
+                (Ident, "sym"),
+                (Whitespace, " "),
+                (Keyword, "from"),
+                (Whitespace, " "),
+                (Ident, "t"),
+                (Whitespace, " "),
+                (Keyword, "where"),
+                (Whitespace, " "),
+                (Ident, "size>0"),
+            ]
+        );
+        assert_eq!(tokens[0].span, 0..6);
+        assert_eq!(tokens[2].span, 7..12);
+        assert_eq!(tokens[14].span, 33..39);
+    }
+
+    #[test]
+    fn test_tokenize_literals_and_comments() {
+        use TokenKind::*;
+
+        let line = "(sum [1 -2.5]) 'AAPL \"a b\" ; total";
+        let kinds: Vec<_> = tokenize(line)
+            .iter()
+            .filter(|t| t.kind != Whitespace)
+            .map(|t| (t.kind, t.text(line)))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (Bracket, "("),
+                (Function, "sum"),
+                (Bracket, "["),
+                (Number, "1"),
+                (Number, "-2.5"),
+                (Bracket, "]"),
+                (Bracket, ")"),
+                (Symbol, "'AAPL"),
+                (String, "\"a b\""),
+                (Comment, "; total"),
+            ]
+        );
+    }
+}
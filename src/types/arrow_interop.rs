@@ -0,0 +1,272 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Arrow `RecordBatch` conversion for tables (enabled by the `arrow` feature).
+//!
+//! Rayforce nulls map to Arrow validity bits: the minimum integer for integer,
+//! date, time and timestamp columns, NaN for floats and the empty symbol for
+//! symbols. Converting back writes the same sentinels for Arrow nulls.
+
+use crate::error::{RayforceError, Result};
+use crate::ffi::{self, RayObj};
+use crate::types::{RayList, RaySymbol, RayTable, RayType, RayVector};
+use crate::*;
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Date32Array, Float64Array, Int16Array, Int32Array, Int64Array,
+    StringArray, Time32MillisecondArray, TimestampNanosecondArray,
+};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use std::sync::Arc;
+
+/// Days from the Unix epoch to the Rayforce epoch (2000-01-01).
+const EPOCH_OFFSET_DAYS: i32 = 10_957;
+
+impl RayTable {
+    /// Convert the table to an Arrow `RecordBatch`, copying column buffers.
+    ///
+    /// | Rayforce | Arrow |
+    /// |----------|-------|
+    /// | `b8` | `Boolean` |
+    /// | `i16` / `i32` / `i64` | `Int16` / `Int32` / `Int64` |
+    /// | `f64` | `Float64` |
+    /// | `symbol`, list of strings | `Utf8` |
+    /// | `date` | `Date32` |
+    /// | `time` | `Time32(Millisecond)` |
+    /// | `timestamp` | `Timestamp(Nanosecond)` |
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        let names = self.columns()?;
+        let mut fields = Vec::with_capacity(names.len());
+        let mut arrays = Vec::with_capacity(names.len());
+
+        for name in &names {
+            let array = column_to_array(name, &self.get_column(name)?)?;
+            fields.push(Field::new(name, array.data_type().clone(), true));
+            arrays.push(array);
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+            .map_err(|e| RayforceError::ConversionError(e.to_string()))
+    }
+
+    /// Build a table from an Arrow `RecordBatch`, copying column buffers.
+    ///
+    /// Accepts the types produced by [`to_record_batch`](Self::to_record_batch);
+    /// `Dictionary` columns with string values are read as symbols.
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<RayTable> {
+        let schema = batch.schema();
+        let mut columns = Vec::with_capacity(batch.num_columns());
+        for (field, array) in schema.fields().iter().zip(batch.columns()) {
+            columns.push((field.name().as_str(), array_to_column(field.name(), array)?));
+        }
+        RayTable::from_dict(columns)
+    }
+}
+
+/// View a vector's storage as `T` after the caller has checked its type code.
+unsafe fn raw_slice<T>(obj: &RayObj) -> &[T] {
+    let len = ffi::get_obj_len(obj) as usize;
+    if len == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(ffi::get_obj_raw_ptr(obj) as *const T, len)
+}
+
+fn with_nulls<T: Copy + PartialEq>(values: &[T], null: T) -> Vec<Option<T>> {
+    values.iter().map(|&v| (v != null).then_some(v)).collect()
+}
+
+fn column_to_array(name: &str, column: &RayObj) -> Result<ArrayRef> {
+    let code = column.type_code();
+    if code < 0 {
+        return Err(RayforceError::ConversionError(format!(
+            "column `{}` is not a vector",
+            name
+        )));
+    }
+
+    let array: ArrayRef = match code as u32 {
+        TYPE_B8 => {
+            let values = unsafe { raw_slice::<u8>(column) };
+            Arc::new(BooleanArray::from(
+                values.iter().map(|&b| b != 0).collect::<Vec<_>>(),
+            ))
+        }
+        TYPE_I16 => Arc::new(Int16Array::from(with_nulls(
+            column.as_slice::<i16>()?,
+            i16::MIN,
+        ))),
+        TYPE_I32 => Arc::new(Int32Array::from(with_nulls(
+            column.as_slice::<i32>()?,
+            i32::MIN,
+        ))),
+        TYPE_I64 => Arc::new(Int64Array::from(with_nulls(
+            column.as_slice::<i64>()?,
+            i64::MIN,
+        ))),
+        TYPE_F64 => {
+            let values = column.as_slice::<f64>()?;
+            Arc::new(Float64Array::from(
+                values
+                    .iter()
+                    .map(|&v| (!v.is_nan()).then_some(v))
+                    .collect::<Vec<_>>(),
+            ))
+        }
+        TYPE_SYMBOL => {
+            let symbols = RayVector::<RaySymbol>::from_ptr(column.clone())?;
+            let values: Vec<Option<String>> = (0..symbols.len())
+                .map(|i| symbols.get(i).filter(|s| !s.is_empty()))
+                .collect();
+            Arc::new(StringArray::from(values))
+        }
+        TYPE_DATE => {
+            let days = with_nulls(unsafe { raw_slice::<i32>(column) }, i32::MIN);
+            Arc::new(Date32Array::from(
+                days.into_iter()
+                    .map(|d| d.map(|d| d + EPOCH_OFFSET_DAYS))
+                    .collect::<Vec<_>>(),
+            ))
+        }
+        TYPE_TIME => Arc::new(Time32MillisecondArray::from(with_nulls(
+            unsafe { raw_slice::<i32>(column) },
+            i32::MIN,
+        ))),
+        TYPE_TIMESTAMP => Arc::new(TimestampNanosecondArray::from(with_nulls(
+            unsafe { raw_slice::<i64>(column) },
+            i64::MIN,
+        ))),
+        TYPE_LIST => {
+            let list = RayList::from_ptr(column.clone())?;
+            let values = list
+                .iter()
+                .map(|cell| String::try_from(cell).map(Some))
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(StringArray::from(values))
+        }
+        _ => {
+            return Err(RayforceError::ConversionError(format!(
+                "column `{}` has unsupported type code {}",
+                name, code
+            )))
+        }
+    };
+    Ok(array)
+}
+
+/// Allocate a vector of `type_code` and copy `values` into its storage.
+fn typed_vector<T: Copy>(type_code: u32, values: &[T]) -> RayObj {
+    unsafe {
+        let obj = RayObj::from_raw(vector(type_code as i8, values.len() as i64));
+        if !values.is_empty() {
+            let dst = ffi::get_obj_raw_ptr(&obj) as *mut T;
+            std::ptr::copy_nonoverlapping(values.as_ptr(), dst, values.len());
+        }
+        obj
+    }
+}
+
+fn downcast<'a, T: 'static>(name: &str, array: &'a ArrayRef) -> Result<&'a T> {
+    array.as_any().downcast_ref::<T>().ok_or_else(|| {
+        RayforceError::ConversionError(format!("column `{}` has an unexpected array layout", name))
+    })
+}
+
+fn array_to_column(name: &str, array: &ArrayRef) -> Result<RayObj> {
+    let column = match array.data_type() {
+        DataType::Boolean => {
+            let values: Vec<u8> = downcast::<BooleanArray>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(false) as u8)
+                .collect();
+            typed_vector(TYPE_B8, &values)
+        }
+        DataType::Int16 => {
+            let values: Vec<i16> = downcast::<Int16Array>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(i16::MIN))
+                .collect();
+            RayObj::from(values)
+        }
+        DataType::Int32 => {
+            let values: Vec<i32> = downcast::<Int32Array>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(i32::MIN))
+                .collect();
+            RayObj::from(values)
+        }
+        DataType::Int64 => {
+            let values: Vec<i64> = downcast::<Int64Array>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(i64::MIN))
+                .collect();
+            RayObj::from(values)
+        }
+        DataType::Float64 => {
+            let values: Vec<f64> = downcast::<Float64Array>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(f64::NAN))
+                .collect();
+            RayObj::from(values)
+        }
+        DataType::Utf8 => {
+            let strings = downcast::<StringArray>(name, array)?;
+            RayVector::<RaySymbol>::from_iter(strings.iter().map(|v| v.unwrap_or("")))
+                .ptr()
+                .clone()
+        }
+        DataType::Dictionary(_, value) if value.as_ref() == &DataType::Utf8 => {
+            let strings = arrow::compute::cast(array, &DataType::Utf8)
+                .map_err(|e| RayforceError::ConversionError(e.to_string()))?;
+            return array_to_column(name, &strings);
+        }
+        DataType::Date32 => {
+            let values: Vec<i32> = downcast::<Date32Array>(name, array)?
+                .iter()
+                .map(|v| v.map_or(i32::MIN, |d| d - EPOCH_OFFSET_DAYS))
+                .collect();
+            typed_vector(TYPE_DATE, &values)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            let values: Vec<i32> = downcast::<Time32MillisecondArray>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(i32::MIN))
+                .collect();
+            typed_vector(TYPE_TIME, &values)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let values: Vec<i64> = downcast::<TimestampNanosecondArray>(name, array)?
+                .iter()
+                .map(|v| v.unwrap_or(i64::MIN))
+                .collect();
+            typed_vector(TYPE_TIMESTAMP, &values)
+        }
+        other => {
+            return Err(RayforceError::ConversionError(format!(
+                "column `{}` has unsupported Arrow type {}",
+                name, other
+            )))
+        }
+    };
+    Ok(column)
+}
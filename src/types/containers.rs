@@ -24,10 +24,12 @@
 //! Container types for Rayforce.
 use crate::error::{RayforceError, Result};
 use crate::ffi::{self, RayObj};
-use crate::types::{RayType, RaySymbol};
+use crate::types::{Operation, RayGuid, RayType, RaySymbol};
 use crate::*;
+use std::cell::OnceCell;
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Index, IndexMut, Range};
 
 /// A generic list that can hold any Rayforce objects.
 #[derive(Clone)]
@@ -71,6 +73,21 @@ impl RayList {
         ffi::push_to_list(&mut self.ptr, item.into());
     }
 
+    /// Append many items at once.
+    ///
+    /// The list is resized a single time and each converted item is moved in
+    /// without cloning. An empty iterator leaves the list untouched; a list
+    /// shared with other handles is copied first. See `benches/list_extend.rs`
+    /// for a comparison with repeated [`push`](Self::push).
+    pub fn extend<T, I>(&mut self, items: I)
+    where
+        T: Into<RayObj>,
+        I: IntoIterator<Item = T>,
+    {
+        let items: Vec<RayObj> = items.into_iter().map(Into::into).collect();
+        ffi::extend_list(&mut self.ptr, items);
+    }
+
     /// Get an item at an index.
     pub fn get(&self, idx: usize) -> Option<RayObj> {
         if idx >= self.len() {
@@ -87,6 +104,87 @@ impl RayList {
         }
     }
 
+    /// Insert an item at an index, shifting later items to the right.
+    ///
+    /// Unlike [`set`](Self::set), which replaces the item at `idx`, this grows
+    /// the list by one. Inserting at `len()` appends.
+    ///
+    /// A list shared with other handles is copied first.
+    ///
+    /// # Panics
+    /// Panics if `idx > len()`, like `Vec::insert`.
+    pub fn insert<T: Into<RayObj>>(&mut self, idx: usize, item: T) {
+        let len = self.len();
+        assert!(idx <= len, "insertion index (is {idx}) should be <= len (is {len})");
+        self.ptr.make_unique();
+        self.push(item);
+        unsafe {
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *mut *mut obj_t;
+            std::slice::from_raw_parts_mut(raw, len + 1)[idx..].rotate_right(1);
+        }
+    }
+
+    /// Remove repeated items, keeping first occurrences in order, with the
+    /// runtime's `distinct`.
+    ///
+    /// Items are compared by value. If the runtime collapses the result into
+    /// a typed vector, its elements are put back into a list.
+    pub fn distinct(&self) -> Result<RayList> {
+        let result = ffi::call_internal(Operation::Distinct.name(), &[&self.ptr])?;
+        if result.is_list() {
+            return RayList::from_ptr(result);
+        }
+        Ok((0..result.len())
+            .filter_map(|idx| ffi::get_at_index(&result, idx))
+            .collect())
+    }
+
+    /// Find the index of the first item equal to `needle`.
+    pub fn find(&self, needle: &RayObj) -> Option<usize> {
+        ffi::find_index(&self.ptr, needle)
+    }
+
+    /// Remove and return the last item.
+    ///
+    /// A list shared with other handles is copied first.
+    pub fn pop(&mut self) -> Option<RayObj> {
+        if self.is_empty() {
+            return None;
+        }
+        self.ptr.make_unique();
+        ffi::pop_from_list(&mut self.ptr)
+    }
+
+    /// Remove and return the item at an index, or `None` if it is out of range.
+    ///
+    /// A list shared with other handles is copied first.
+    pub fn remove(&mut self, idx: usize) -> Option<RayObj> {
+        let item = self.get(idx)?;
+        self.ptr.make_unique();
+        ffi::remove_at_index(&mut self.ptr, idx as i64);
+        Some(item)
+    }
+
+    /// Remove the items at the given indices.
+    ///
+    /// Indices may be unsorted or repeated; out-of-range indices are ignored.
+    /// A list shared with other handles is copied first.
+    pub fn remove_many(&mut self, indices: &[usize]) {
+        let len = self.len();
+        let mut ids: Vec<i64> = indices
+            .iter()
+            .filter(|&&idx| idx < len)
+            .map(|&idx| idx as i64)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.is_empty() {
+            return;
+        }
+        self.ptr.make_unique();
+        ffi::remove_at_indices(&mut self.ptr, &ids);
+    }
+
     /// Iterate over items as RayObj.
     pub fn iter(&self) -> impl Iterator<Item = RayObj> + '_ {
         (0..self.len()).filter_map(move |i| self.get(i))
@@ -136,6 +234,12 @@ impl<T: Into<RayObj>> FromIterator<T> for RayList {
     }
 }
 
+impl<T: Into<RayObj>> Extend<T> for RayList {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        RayList::extend(self, iter)
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type List = RayList;
 
@@ -143,6 +247,8 @@ pub type List = RayList;
 pub struct RayVector<T> {
     ptr: RayObj,
     _marker: PhantomData<T>,
+    // Narrowed copy backing `RayVector<f32>::as_slice`; unused for other types.
+    narrow: OnceCell<Box<[f32]>>,
 }
 
 impl<T> Clone for RayVector<T> {
@@ -150,6 +256,7 @@ impl<T> Clone for RayVector<T> {
         Self {
             ptr: self.ptr.clone(),
             _marker: PhantomData,
+            narrow: OnceCell::new(),
         }
     }
 }
@@ -176,6 +283,50 @@ impl<T> RayVector<T> {
     }
 }
 
+/// Deduplication and set operations, backed by the runtime's `distinct`,
+/// `union`, `inter` and `except`.
+///
+/// Results keep first-occurrence order: `union` lists the elements of
+/// `self` and then those of `other` not seen yet, without repeats, while
+/// `intersect` and `difference` keep the elements of `self` in their
+/// original order.
+impl<T> RayVector<T>
+where
+    Self: RayType,
+{
+    /// Remove repeated elements, keeping first occurrences in order, like
+    /// `(distinct x)`.
+    pub fn distinct(&self) -> Result<Self> {
+        <Self as RayType>::from_ptr(ffi::call_internal(Operation::Distinct.name(), &[&self.ptr])?)
+    }
+
+    /// Get the elements found in either vector, like `(union x y)`.
+    pub fn union(&self, other: &Self) -> Result<Self> {
+        self.set_op("union", other)
+    }
+
+    /// Get the elements of `self` also found in `other`, like `(inter x y)`.
+    pub fn intersect(&self, other: &Self) -> Result<Self> {
+        self.set_op("inter", other)
+    }
+
+    /// Get the elements of `self` not found in `other`, like `(except x y)`.
+    pub fn difference(&self, other: &Self) -> Result<Self> {
+        self.set_op("except", other)
+    }
+
+    fn set_op(&self, name: &str, other: &Self) -> Result<Self> {
+        <Self as RayType>::from_ptr(ffi::call_internal(name, &[&self.ptr, &other.ptr])?)
+    }
+}
+
+/// Moves the vector's object out without an extra reference.
+impl<T> From<RayVector<T>> for RayObj {
+    fn from(v: RayVector<T>) -> Self {
+        v.ptr
+    }
+}
+
 impl<T> fmt::Debug for RayVector<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "RayVector<{}>[{}]", std::any::type_name::<T>(), self.len())
@@ -196,6 +347,7 @@ impl RayVector<i64> {
             Self {
                 ptr: RayObj::from_raw(vector(TYPE_I64 as i8, len as i64)),
                 _marker: PhantomData,
+                narrow: OnceCell::new(),
             }
         }
     }
@@ -205,6 +357,7 @@ impl RayVector<i64> {
         Self {
             ptr: RayObj::from(data),
             _marker: PhantomData,
+            narrow: OnceCell::new(),
         }
     }
 
@@ -223,8 +376,9 @@ impl RayVector<i64> {
         }
     }
 
-    /// Get the data as a mutable slice.
+    /// Get the data as a mutable slice, copying it first if it is shared.
     pub fn as_mut_slice(&mut self) -> &mut [i64] {
+        self.ptr.make_unique();
         unsafe {
             let len = ffi::get_obj_len(&self.ptr) as usize;
             let raw = ffi::get_obj_raw_ptr(&self.ptr) as *mut i64;
@@ -247,6 +401,83 @@ impl RayVector<i64> {
             self.as_mut_slice()[idx] = value;
         }
     }
+
+    /// Iterate over the elements without allocating per item.
+    pub fn iter(&self) -> std::slice::Iter<'_, i64> {
+        self.as_slice().iter()
+    }
+
+    /// Find the index of the first element equal to `value`.
+    pub fn position(&self, value: i64) -> Option<usize> {
+        ffi::find_raw_i64(&self.ptr, value)
+    }
+
+    /// Return a new vector with the elements of `self` followed by `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut out = Self::new(self.len() + other.len());
+        let (head, tail) = out.as_mut_slice().split_at_mut(self.len());
+        head.copy_from_slice(self.as_slice());
+        tail.copy_from_slice(other.as_slice());
+        out
+    }
+
+    /// Copy the elements in `range` into a new vector.
+    ///
+    /// The result owns its data; later writes to either vector do not affect
+    /// the other.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds, like slice indexing.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        Self::from_slice(&self.as_slice()[range])
+    }
+
+    /// Get the permutation that sorts the vector, like `iasc`/`idesc`.
+    ///
+    /// The sort is stable: equal elements keep their original order in both
+    /// directions. Apply the result with [`RayObj::take`].
+    pub fn argsort(&self, descending: bool) -> RayVector<i64> {
+        let values = self.as_slice();
+        let mut order: Vec<i64> = (0..values.len() as i64).collect();
+        if descending {
+            order.sort_by(|&a, &b| values[b as usize].cmp(&values[a as usize]));
+        } else {
+            order.sort_by_key(|&i| values[i as usize]);
+        }
+        RayVector::<i64>::from_slice(&order)
+    }
+
+    /// Resize to `new_len`, filling new slots with `fill` when growing.
+    ///
+    /// The vector is copied first if it is shared.
+    pub fn resize(&mut self, new_len: usize, fill: i64) {
+        let old_len = self.len();
+        self.ptr.make_unique();
+        ffi::resize_vector(&mut self.ptr, new_len as i64);
+        if new_len > old_len {
+            self.as_mut_slice()[old_len..].fill(fill);
+        }
+    }
+
+    /// Shorten to `new_len` elements; does nothing if already shorter.
+    ///
+    /// The vector is copied first if it is shared.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            self.ptr.make_unique();
+            ffi::resize_vector(&mut self.ptr, new_len as i64);
+        }
+    }
+
+    /// Apply a Rayforce function to each element with the runtime's `map`.
+    ///
+    /// `func` must be a Rayforce function object, such as a lambda from
+    /// `eval("(fn [x] (* x x))")` or a built-in, not a Rust closure. The loop
+    /// runs inside the runtime, and the result is whatever `map` returns,
+    /// usually a vector when every call returns an atom of the same type.
+    pub fn map_ray(&self, func: &RayObj) -> Result<RayObj> {
+        ffi::call_internal(Operation::Map.name(), &[func, &self.ptr])
+    }
 }
 
 impl RayType for RayVector<i64> {
@@ -260,7 +491,7 @@ impl RayType for RayVector<i64> {
                 actual: format!("type code {}", ptr.type_code()),
             });
         }
-        Ok(Self { ptr, _marker: PhantomData })
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
     }
 
     fn ptr(&self) -> &RayObj {
@@ -268,12 +499,223 @@ impl RayType for RayVector<i64> {
     }
 }
 
+impl<'a> IntoIterator for &'a RayVector<i64> {
+    type Item = &'a i64;
+    type IntoIter = std::slice::Iter<'a, i64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Deref for RayVector<i64> {
+    type Target = [i64];
+
+    fn deref(&self) -> &[i64] {
+        self.as_slice()
+    }
+}
+
+/// Mutable access goes through [`as_mut_slice`](RayVector::as_mut_slice), so
+/// a vector shared with other handles is copied before the first write.
+impl DerefMut for RayVector<i64> {
+    fn deref_mut(&mut self) -> &mut [i64] {
+        self.as_mut_slice()
+    }
+}
+
+impl Index<usize> for RayVector<i64> {
+    type Output = i64;
+
+    fn index(&self, idx: usize) -> &i64 {
+        let len = self.len();
+        self.as_slice()
+            .get(idx)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {idx}"))
+    }
+}
+
+impl IndexMut<usize> for RayVector<i64> {
+    fn index_mut(&mut self, idx: usize) -> &mut i64 {
+        let len = self.len();
+        self.as_mut_slice()
+            .get_mut(idx)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {idx}"))
+    }
+}
+
 impl FromIterator<i64> for RayVector<i64> {
     fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
         RayVector::<i64>::from_slice(&iter.into_iter().collect::<Vec<_>>())
     }
 }
 
+// RayVector of i32
+impl RayVector<i32> {
+    /// Create a new i32 vector.
+    pub fn new(len: usize) -> Self {
+        unsafe {
+            Self {
+                ptr: RayObj::from_raw(vector(TYPE_I32 as i8, len as i64)),
+                _marker: PhantomData,
+                narrow: OnceCell::new(),
+            }
+        }
+    }
+
+    /// Create from a slice.
+    pub fn from_slice(data: &[i32]) -> Self {
+        Self {
+            ptr: RayObj::from(data),
+            _marker: PhantomData,
+            narrow: OnceCell::new(),
+        }
+    }
+
+    /// Get the data as a slice.
+    pub fn as_slice(&self) -> &[i32] {
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *const i32;
+            std::slice::from_raw_parts(raw, len)
+        }
+    }
+
+    /// Get the data as a mutable slice, copying it first if it is shared.
+    pub fn as_mut_slice(&mut self) -> &mut [i32] {
+        self.ptr.make_unique();
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *mut i32;
+            std::slice::from_raw_parts_mut(raw, len)
+        }
+    }
+
+    /// Get an element.
+    pub fn get(&self, idx: usize) -> Option<i32> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.as_slice()[idx])
+        }
+    }
+
+    /// Set an element.
+    pub fn set(&mut self, idx: usize, value: i32) {
+        if idx < self.len() {
+            self.as_mut_slice()[idx] = value;
+        }
+    }
+}
+
+impl RayType for RayVector<i32> {
+    const TYPE_CODE: i8 = TYPE_I32 as i8;
+    const RAY_NAME: &'static str = "RayVector<i32>";
+
+    fn from_ptr(ptr: RayObj) -> Result<Self> {
+        if ptr.type_code() != Self::TYPE_CODE {
+            return Err(RayforceError::TypeMismatch {
+                expected: Self::RAY_NAME.into(),
+                actual: format!("type code {}", ptr.type_code()),
+            });
+        }
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
+    }
+
+    fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+}
+
+impl FromIterator<i32> for RayVector<i32> {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        RayVector::<i32>::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+// RayVector of i16
+impl RayVector<i16> {
+    /// Create a new i16 vector.
+    pub fn new(len: usize) -> Self {
+        unsafe {
+            Self {
+                ptr: RayObj::from_raw(vector(TYPE_I16 as i8, len as i64)),
+                _marker: PhantomData,
+                narrow: OnceCell::new(),
+            }
+        }
+    }
+
+    /// Create from a slice.
+    pub fn from_slice(data: &[i16]) -> Self {
+        Self {
+            ptr: RayObj::from(data),
+            _marker: PhantomData,
+            narrow: OnceCell::new(),
+        }
+    }
+
+    /// Get the data as a slice.
+    pub fn as_slice(&self) -> &[i16] {
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *const i16;
+            std::slice::from_raw_parts(raw, len)
+        }
+    }
+
+    /// Get the data as a mutable slice, copying it first if it is shared.
+    pub fn as_mut_slice(&mut self) -> &mut [i16] {
+        self.ptr.make_unique();
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *mut i16;
+            std::slice::from_raw_parts_mut(raw, len)
+        }
+    }
+
+    /// Get an element.
+    pub fn get(&self, idx: usize) -> Option<i16> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.as_slice()[idx])
+        }
+    }
+
+    /// Set an element.
+    pub fn set(&mut self, idx: usize, value: i16) {
+        if idx < self.len() {
+            self.as_mut_slice()[idx] = value;
+        }
+    }
+}
+
+impl RayType for RayVector<i16> {
+    const TYPE_CODE: i8 = TYPE_I16 as i8;
+    const RAY_NAME: &'static str = "RayVector<i16>";
+
+    fn from_ptr(ptr: RayObj) -> Result<Self> {
+        if ptr.type_code() != Self::TYPE_CODE {
+            return Err(RayforceError::TypeMismatch {
+                expected: Self::RAY_NAME.into(),
+                actual: format!("type code {}", ptr.type_code()),
+            });
+        }
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
+    }
+
+    fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+}
+
+impl FromIterator<i16> for RayVector<i16> {
+    fn from_iter<I: IntoIterator<Item = i16>>(iter: I) -> Self {
+        RayVector::<i16>::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
 // RayVector of f64
 impl RayVector<f64> {
     /// Create a new f64 vector.
@@ -282,6 +724,7 @@ impl RayVector<f64> {
             Self {
                 ptr: RayObj::from_raw(vector(TYPE_F64 as i8, len as i64)),
                 _marker: PhantomData,
+                narrow: OnceCell::new(),
             }
         }
     }
@@ -291,6 +734,7 @@ impl RayVector<f64> {
         Self {
             ptr: RayObj::from(data),
             _marker: PhantomData,
+            narrow: OnceCell::new(),
         }
     }
 
@@ -309,8 +753,9 @@ impl RayVector<f64> {
         }
     }
 
-    /// Get the data as a mutable slice.
+    /// Get the data as a mutable slice, copying it first if it is shared.
     pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        self.ptr.make_unique();
         unsafe {
             let len = ffi::get_obj_len(&self.ptr) as usize;
             let raw = ffi::get_obj_raw_ptr(&self.ptr) as *mut f64;
@@ -333,6 +778,55 @@ impl RayVector<f64> {
             self.as_mut_slice()[idx] = value;
         }
     }
+
+    /// Iterate over the elements without allocating per item.
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.as_slice().iter()
+    }
+
+    /// Resize to `new_len`, filling new slots with `fill` when growing.
+    ///
+    /// The vector is copied first if it is shared.
+    pub fn resize(&mut self, new_len: usize, fill: f64) {
+        let old_len = self.len();
+        self.ptr.make_unique();
+        ffi::resize_vector(&mut self.ptr, new_len as i64);
+        if new_len > old_len {
+            self.as_mut_slice()[old_len..].fill(fill);
+        }
+    }
+
+    /// Shorten to `new_len` elements; does nothing if already shorter.
+    ///
+    /// The vector is copied first if it is shared.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            self.ptr.make_unique();
+            ffi::resize_vector(&mut self.ptr, new_len as i64);
+        }
+    }
+
+    /// Get the permutation that sorts the vector, like `iasc`/`idesc`.
+    ///
+    /// Nulls (NaN) sort before every other value, as in the runtime. The
+    /// sort is stable: equal elements keep their original order in both
+    /// directions. Apply the result with [`RayObj::take`].
+    pub fn argsort(&self, descending: bool) -> RayVector<i64> {
+        let values = self.as_slice();
+        let cmp = |a: f64, b: f64| match (a.is_nan(), b.is_nan()) {
+            (true, true) => std::cmp::Ordering::Equal,
+            (true, false) => std::cmp::Ordering::Less,
+            (false, true) => std::cmp::Ordering::Greater,
+            (false, false) => a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal),
+        };
+        let mut order: Vec<i64> = (0..values.len() as i64).collect();
+        if descending {
+            order.sort_by(|&a, &b| cmp(values[b as usize], values[a as usize]));
+        } else {
+            order.sort_by(|&a, &b| cmp(values[a as usize], values[b as usize]));
+        }
+        RayVector::<i64>::from_slice(&order)
+    }
 }
 
 impl RayType for RayVector<f64> {
@@ -346,7 +840,7 @@ impl RayType for RayVector<f64> {
                 actual: format!("type code {}", ptr.type_code()),
             });
         }
-        Ok(Self { ptr, _marker: PhantomData })
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
     }
 
     fn ptr(&self) -> &RayObj {
@@ -354,12 +848,170 @@ impl RayType for RayVector<f64> {
     }
 }
 
+impl<'a> IntoIterator for &'a RayVector<f64> {
+    type Item = &'a f64;
+    type IntoIter = std::slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Deref for RayVector<f64> {
+    type Target = [f64];
+
+    fn deref(&self) -> &[f64] {
+        self.as_slice()
+    }
+}
+
+/// Mutable access goes through [`as_mut_slice`](RayVector::as_mut_slice), so
+/// a vector shared with other handles is copied before the first write.
+impl DerefMut for RayVector<f64> {
+    fn deref_mut(&mut self) -> &mut [f64] {
+        self.as_mut_slice()
+    }
+}
+
+impl Index<usize> for RayVector<f64> {
+    type Output = f64;
+
+    fn index(&self, idx: usize) -> &f64 {
+        let len = self.len();
+        self.as_slice()
+            .get(idx)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {idx}"))
+    }
+}
+
+impl IndexMut<usize> for RayVector<f64> {
+    fn index_mut(&mut self, idx: usize) -> &mut f64 {
+        let len = self.len();
+        self.as_mut_slice()
+            .get_mut(idx)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {idx}"))
+    }
+}
+
 impl FromIterator<f64> for RayVector<f64> {
     fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
         RayVector::<f64>::from_slice(&iter.into_iter().collect::<Vec<_>>())
     }
 }
 
+// RayVector of f32
+//
+// The runtime has no single-precision type code, so the values are widened
+// into an f64 vector on the way in and narrowed on the way out. The object
+// the runtime sees is an ordinary f64 column, so it is safe to query, send
+// over IPC or convert into a `RayObj`. `as_slice` narrows the column once
+// into an owned f32 buffer, which `set` keeps in step with the column.
+impl RayVector<f32> {
+    /// Create a new f32 vector of zeros.
+    pub fn new(len: usize) -> Self {
+        Self::from_slice(&vec![0.0; len])
+    }
+
+    /// Create from a slice, widening each value to f64.
+    pub fn from_slice(data: &[f32]) -> Self {
+        Self {
+            ptr: data.iter().map(|&v| v as f64).collect::<RayVector<f64>>().ptr,
+            _marker: PhantomData,
+            narrow: OnceCell::new(),
+        }
+    }
+
+    /// Get the data as an f32 slice.
+    ///
+    /// The first call narrows the f64 column into a buffer owned by this
+    /// handle, so the slice takes half the bytes of the column but is a copy
+    /// of it rather than a view into runtime memory.
+    pub fn as_slice(&self) -> &[f32] {
+        self.narrow
+            .get_or_init(|| self.widened().iter().map(|&v| v as f32).collect())
+    }
+
+    /// Iterate over the elements of the narrowed buffer.
+    pub fn iter(&self) -> std::slice::Iter<'_, f32> {
+        self.as_slice().iter()
+    }
+
+    /// Get an element, narrowed to f32.
+    pub fn get(&self, idx: usize) -> Option<f32> {
+        self.widened().get(idx).map(|&v| v as f32)
+    }
+
+    /// Set an element, copying the vector first if it is shared.
+    pub fn set(&mut self, idx: usize, value: f32) {
+        if idx < self.len() {
+            self.ptr.make_unique();
+            unsafe {
+                *(ffi::get_obj_raw_ptr(&self.ptr) as *mut f64).add(idx) = value as f64;
+            }
+            if let Some(narrow) = self.narrow.get_mut() {
+                narrow[idx] = value;
+            }
+        }
+    }
+
+    /// Copy the elements out, narrowed to f32.
+    pub fn to_vec(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
+
+    /// Get the f64 column backing this vector; shares the data, no copy.
+    pub fn to_f64(&self) -> RayVector<f64> {
+        RayVector {
+            ptr: self.ptr.clone(),
+            _marker: PhantomData,
+            narrow: OnceCell::new(),
+        }
+    }
+
+    fn widened(&self) -> &[f64] {
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *const f64;
+            std::slice::from_raw_parts(raw, len)
+        }
+    }
+}
+
+/// An f32 vector wraps an f64 column, so `from_ptr` accepts any f64 vector.
+impl RayType for RayVector<f32> {
+    const TYPE_CODE: i8 = TYPE_F64 as i8;
+    const RAY_NAME: &'static str = "RayVector<f32>";
+
+    fn from_ptr(ptr: RayObj) -> Result<Self> {
+        if ptr.type_code() != Self::TYPE_CODE {
+            return Err(RayforceError::TypeMismatch {
+                expected: Self::RAY_NAME.into(),
+                actual: format!("type code {}", ptr.type_code()),
+            });
+        }
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
+    }
+
+    fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+}
+
+impl<'a> IntoIterator for &'a RayVector<f32> {
+    type Item = &'a f32;
+    type IntoIter = std::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<f32> for RayVector<f32> {
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Self {
+        RayVector::<f32>::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
 // RayVector of RaySymbol
 impl RayVector<RaySymbol> {
     /// Create a new symbol vector.
@@ -368,30 +1020,40 @@ impl RayVector<RaySymbol> {
             Self {
                 ptr: RayObj::from_raw(vector(TYPE_SYMBOL as i8, len as i64)),
                 _marker: PhantomData,
+                narrow: OnceCell::new(),
             }
         }
     }
 
     /// Create from an iterator of strings.
+    ///
+    /// Each string is interned directly into the vector, without allocating
+    /// a symbol atom per element.
     pub fn from_iter<S, I>(iter: I) -> Self
     where
         S: AsRef<str>,
         I: IntoIterator<Item = S>,
     {
         let items: Vec<_> = iter.into_iter().collect();
+        Self {
+            ptr: ffi::intern_symbols(&items),
+            _marker: PhantomData,
+            narrow: OnceCell::new(),
+        }
+    }
+
+    /// Find the index of the first occurrence of a symbol.
+    ///
+    /// The lookup compares the text of the stored symbols, so searching for a
+    /// string does not intern it; a string that was never interned is simply
+    /// not found.
+    pub fn position(&self, s: &str) -> Option<usize> {
         unsafe {
-            let obj = vector(TYPE_SYMBOL as i8, items.len() as i64);
-            let dst = ffi::get_obj_raw_ptr(&RayObj::from_raw(clone_obj(obj))) as *mut i64;
-            for (i, s) in items.iter().enumerate() {
-                // Intern the symbol and get its ID
-                let sym = ffi::new_symbol(s.as_ref());
-                let id = *(*sym.as_ptr()).__bindgen_anon_1.i64_.as_ref();
-                *dst.add(i) = id;
-            }
-            Self {
-                ptr: RayObj::from_raw(obj),
-                _marker: PhantomData,
-            }
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *const i64;
+            (0..self.len()).find(|&idx| {
+                let cstr = str_from_symbol(*raw.add(idx));
+                !cstr.is_null() && std::ffi::CStr::from_ptr(cstr).to_bytes() == s.as_bytes()
+            })
         }
     }
 
@@ -424,7 +1086,70 @@ impl RayType for RayVector<RaySymbol> {
                 actual: format!("type code {}", ptr.type_code()),
             });
         }
-        Ok(Self { ptr, _marker: PhantomData })
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
+    }
+
+    fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+}
+
+impl From<&[&str]> for RayVector<RaySymbol> {
+    fn from(items: &[&str]) -> Self {
+        Self::from_iter(items)
+    }
+}
+
+impl From<&[String]> for RayVector<RaySymbol> {
+    fn from(items: &[String]) -> Self {
+        Self::from_iter(items)
+    }
+}
+
+impl From<Vec<String>> for RayVector<RaySymbol> {
+    fn from(items: Vec<String>) -> Self {
+        Self::from_iter(items)
+    }
+}
+
+// RayVector of RayGuid
+impl RayVector<RayGuid> {
+    /// Create a new GUID vector.
+    pub fn new(len: usize) -> Self {
+        unsafe {
+            Self {
+                ptr: RayObj::from_raw(vector(TYPE_GUID as i8, len as i64)),
+                _marker: PhantomData,
+                narrow: OnceCell::new(),
+            }
+        }
+    }
+
+    /// Get a GUID at an index.
+    pub fn get(&self, idx: usize) -> Option<uuid::Uuid> {
+        if idx >= self.len() {
+            return None;
+        }
+        unsafe {
+            let raw = ffi::get_obj_raw_ptr(&self.ptr) as *const u8;
+            let bytes = std::slice::from_raw_parts(raw.add(idx * 16), 16);
+            uuid::Uuid::from_slice(bytes).ok()
+        }
+    }
+}
+
+impl RayType for RayVector<RayGuid> {
+    const TYPE_CODE: i8 = TYPE_GUID as i8;
+    const RAY_NAME: &'static str = "RayVector<RayGuid>";
+
+    fn from_ptr(ptr: RayObj) -> Result<Self> {
+        if ptr.type_code() != Self::TYPE_CODE {
+            return Err(RayforceError::TypeMismatch {
+                expected: Self::RAY_NAME.into(),
+                actual: format!("type code {}", ptr.type_code()),
+            });
+        }
+        Ok(Self { ptr, _marker: PhantomData, narrow: OnceCell::new() })
     }
 
     fn ptr(&self) -> &RayObj {
@@ -432,6 +1157,133 @@ impl RayType for RayVector<RaySymbol> {
     }
 }
 
+/// Build a GUID vector from UUIDs, copying each 16-byte value in place.
+impl FromIterator<uuid::Uuid> for RayVector<RayGuid> {
+    fn from_iter<I: IntoIterator<Item = uuid::Uuid>>(iter: I) -> Self {
+        let items: Vec<uuid::Uuid> = iter.into_iter().collect();
+        let out = Self::new(items.len());
+        unsafe {
+            let dst = ffi::get_obj_raw_ptr(&out.ptr);
+            for (i, uuid) in items.iter().enumerate() {
+                std::ptr::copy_nonoverlapping(uuid.as_bytes().as_ptr(), dst.add(i * 16), 16);
+            }
+        }
+        out
+    }
+}
+
+/// An enumerated symbol vector: indices into a domain of distinct symbols.
+///
+/// Large symbol columns are stored this way so each row holds a small index
+/// rather than a symbol. The domain and indices are read from the runtime's
+/// enum object with `key` and `value`.
+#[derive(Clone)]
+pub struct RayEnum {
+    ptr: RayObj,
+}
+
+impl RayEnum {
+    /// Enumerate `values` against `domain` with the runtime's `enumerate`.
+    ///
+    /// Every value must appear in the domain; if the runtime rejects the
+    /// values, the first one missing from the domain is reported as
+    /// `KeyNotFound`.
+    pub fn enumerate(
+        domain: &RayVector<RaySymbol>,
+        values: &RayVector<RaySymbol>,
+    ) -> Result<Self> {
+        unsafe {
+            let obj = enumerate(domain.ptr.as_ptr(), values.ptr.as_ptr());
+            if obj.is_null() {
+                return Err(RayforceError::AllocationFailed);
+            }
+            let obj = RayObj::from_raw(obj);
+            if obj.is_error() {
+                let missing = (0..values.len())
+                    .filter_map(|idx| values.get(idx))
+                    .find(|value| domain.position(value).is_none());
+                return Err(match missing {
+                    Some(value) => RayforceError::KeyNotFound(value),
+                    None => RayforceError::EvalFailed(ffi::get_error_message(obj.as_ptr())),
+                });
+            }
+            Self::from_ptr(obj)
+        }
+    }
+
+    /// Get the number of elements.
+    pub fn len(&self) -> usize {
+        self.indices().map_or(0, |indices| indices.len())
+    }
+
+    /// Check if the enum is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the symbol at an index, resolved through the domain.
+    pub fn get(&self, idx: usize) -> Option<String> {
+        let pos = *self.indices().ok()?.as_slice().get(idx)?;
+        self.domain().ok()?.get(usize::try_from(pos).ok()?)
+    }
+
+    /// Get the domain the values are enumerated against.
+    pub fn domain(&self) -> Result<RayVector<RaySymbol>> {
+        RayVector::<RaySymbol>::from_ptr(self.part(ray_key)?)
+    }
+
+    /// Get the index of each element in the domain.
+    pub fn indices(&self) -> Result<RayVector<i64>> {
+        RayVector::<i64>::from_ptr(self.part(ray_value)?)
+    }
+
+    /// Read the domain (`key`) or indices (`value`) of the enum.
+    fn part(&self, accessor: unsafe extern "C" fn(obj_p) -> obj_p) -> Result<RayObj> {
+        unsafe {
+            let obj = accessor(self.ptr.as_ptr());
+            if obj.is_null() {
+                return Err(RayforceError::NullPointer);
+            }
+            let obj = RayObj::from_raw(obj);
+            if obj.is_error() {
+                return Err(RayforceError::EvalFailed(ffi::get_error_message(obj.as_ptr())));
+            }
+            Ok(obj)
+        }
+    }
+}
+
+impl RayType for RayEnum {
+    const TYPE_CODE: i8 = TYPE_ENUM as i8;
+    const RAY_NAME: &'static str = "RayEnum";
+
+    fn from_ptr(ptr: RayObj) -> Result<Self> {
+        if ptr.type_code() != Self::TYPE_CODE {
+            return Err(RayforceError::TypeMismatch {
+                expected: Self::RAY_NAME.into(),
+                actual: format!("type code {}", ptr.type_code()),
+            });
+        }
+        Ok(Self { ptr })
+    }
+
+    fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+}
+
+impl fmt::Debug for RayEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RayEnum[{}]", self.len())
+    }
+}
+
+impl fmt::Display for RayEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ptr)
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type Vector<T> = RayVector<T>;
 
@@ -459,6 +1311,34 @@ impl RayString {
         }
     }
 
+    /// Get the raw bytes of the string.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            let len = ffi::get_obj_len(&self.ptr) as usize;
+            std::slice::from_raw_parts(ffi::get_obj_raw_ptr(&self.ptr), len)
+        }
+    }
+
+    /// Append `s`, copying the buffer first if it is shared.
+    pub fn push_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        self.ptr.make_unique();
+        let old_len = self.len();
+        ffi::resize_vector(&mut self.ptr, (old_len + s.len()) as i64);
+        unsafe {
+            let dst = ffi::get_obj_raw_ptr(&self.ptr).add(old_len);
+            std::ptr::copy_nonoverlapping(s.as_ptr(), dst, s.len());
+        }
+    }
+
+    /// Concatenate with another string using the runtime's `concat`.
+    pub fn concat(&self, other: &RayString) -> Result<RayString> {
+        let result = ffi::call_internal(Operation::Concat.name(), &[&self.ptr, &other.ptr])?;
+        RayString::from_ptr(result)
+    }
+
     /// Get the length.
     pub fn len(&self) -> usize {
         self.ptr.len() as usize
@@ -545,6 +1425,14 @@ impl RayDict {
             if d.is_null() {
                 return Err(RayforceError::AllocationFailed);
             }
+            if (*d).type_ == TYPE_ERR as i8 {
+                let msg = ffi::get_error_message(d);
+                drop_obj(d);
+                return Err(RayforceError::ConversionError(format!(
+                    "dictionary construction failed: {}",
+                    msg
+                )));
+            }
             std::mem::forget(keys);
             std::mem::forget(values);
             Ok(Self {
@@ -566,6 +1454,35 @@ impl RayDict {
         }
     }
 
+    /// Insert a value, replacing the existing value if the key is present.
+    ///
+    /// A dict shared with other handles is copied first.
+    pub fn insert<T: Into<RayObj>>(&mut self, key: &str, value: T) -> Result<()> {
+        self.ptr.make_unique();
+        ffi::set_by_key(&mut self.ptr, &ffi::new_symbol(key), value.into())
+    }
+
+    /// Remove a key, returning its value if it was present.
+    ///
+    /// The entry is removed in place. A dict, keys or values shared with other
+    /// handles are copied first, so those handles are unaffected.
+    pub fn remove(&mut self, key: &str) -> Option<RayObj> {
+        let value = self.get(key)?;
+        let pos = RayVector::<RaySymbol>::from_ptr(self.keys()).ok()?.position(key)?;
+
+        self.ptr.make_unique();
+        unsafe {
+            // Dict is structured as [keys, values]
+            let slots = ffi::get_obj_raw_ptr(&self.ptr) as *mut *mut obj_t;
+            for i in 0..2 {
+                let slot = slots.add(i);
+                *slot = cow_obj(*slot);
+                remove_idx(slot, pos as i64);
+            }
+        }
+        Some(value)
+    }
+
     /// Get the keys.
     pub fn keys(&self) -> RayObj {
         unsafe {
@@ -609,6 +1526,93 @@ impl RayDict {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Iterate over `(key, value)` pairs in insertion order.
+    pub fn iter(&self) -> RayDictIter {
+        let keys = RayVector::<RaySymbol>::from_ptr(self.keys())
+            .map(|keys| (0..keys.len()).filter_map(|i| keys.get(i)).collect::<Vec<_>>())
+            .unwrap_or_default();
+        RayDictIter {
+            keys: keys.into_iter(),
+            values: self.values(),
+            idx: 0,
+        }
+    }
+
+    /// Turn a dictionary of columns into a table, like `(flip d)`.
+    ///
+    /// The keys must be symbols and every value a vector or list of the same
+    /// length; otherwise `TypeMismatch` or `QueryError` is returned. The
+    /// table shares the dictionary's key and column objects rather than
+    /// copying them.
+    pub fn flip(&self) -> Result<crate::types::RayTable> {
+        let keys = self.keys();
+        if keys.type_code() != TYPE_SYMBOL as i8 {
+            return Err(RayforceError::TypeMismatch {
+                expected: "symbol keys".into(),
+                actual: format!("type code {}", keys.type_code()),
+            });
+        }
+        let values = self.values();
+        if !values.is_list() {
+            return Err(RayforceError::QueryError("flip requires vector values".into()));
+        }
+
+        let mut rows = None;
+        for idx in 0..values.len() {
+            let column = ffi::get_at_index(&values, idx).ok_or(RayforceError::NullPointer)?;
+            if !column.is_vector() && !column.is_list() {
+                return Err(RayforceError::QueryError(format!(
+                    "flip requires vector values, got type code {}",
+                    column.type_code()
+                )));
+            }
+            match rows {
+                None => rows = Some(column.len()),
+                Some(len) if len != column.len() => {
+                    return Err(RayforceError::QueryError(format!(
+                        "flip requires equal-length columns, got {} and {}",
+                        len,
+                        column.len()
+                    )));
+                }
+                Some(_) => {}
+            }
+        }
+
+        crate::types::RayTable::from_ptr(ffi::new_table(keys, values)?)
+    }
+}
+
+/// Iterator over the `(key, value)` pairs of a [`RayDict`].
+pub struct RayDictIter {
+    keys: std::vec::IntoIter<String>,
+    values: RayObj,
+    idx: usize,
+}
+
+impl Iterator for RayDictIter {
+    type Item = (String, RayObj);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let value = ffi::get_at_index(&self.values, self.idx as i64)?;
+        self.idx += 1;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl IntoIterator for &RayDict {
+    type Item = (String, RayObj);
+    type IntoIter = RayDictIter;
+
+    fn into_iter(self) -> RayDictIter {
+        self.iter()
+    }
 }
 
 impl RayType for RayDict {
@@ -644,3 +1648,107 @@ impl fmt::Display for RayDict {
 
 /// Type alias for backward compatibility.
 pub type Dict = RayDict;
+
+/// A map with arbitrary keys, such as integers or strings.
+///
+/// [`RayDict`] is keyed by a symbol vector, which is what queries and tables
+/// use. `RayAnyMap` is a runtime dict whose keys are a generic list, so any
+/// object can be a key. Keys are compared by value with the runtime's `find`,
+/// which makes each lookup linear in the number of keys. The runtime's
+/// `anymap` function is unrelated: it maps a column onto a domain, like
+/// `enumerate`.
+#[derive(Clone)]
+pub struct RayAnyMap {
+    ptr: RayObj,
+}
+
+impl RayAnyMap {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::from_parts(RayList::new(), RayList::new())
+    }
+
+    fn from_parts(keys: RayList, values: RayList) -> Self {
+        let ptr = ffi::new_dict(keys.ptr, values.ptr).expect("allocating a dict of two lists");
+        Self { ptr }
+    }
+
+    /// Insert a value, replacing the existing value if the key is present.
+    ///
+    /// A map shared with other handles is copied first.
+    pub fn insert(&mut self, key: impl Into<RayObj>, value: impl Into<RayObj>) {
+        let key = key.into();
+        let mut keys = RayList { ptr: self.part(0) };
+        let mut values = RayList { ptr: self.part(1) };
+        // Release the dict's references so unshared lists are updated in place.
+        self.ptr = ffi::new_list();
+
+        match keys.find(&key) {
+            Some(pos) => {
+                values.ptr.make_unique();
+                values.set(pos, value);
+            }
+            None => {
+                keys.push(key);
+                values.push(value);
+            }
+        }
+        *self = Self::from_parts(keys, values);
+    }
+
+    /// Get a value by key, or `None` if the key is not present.
+    pub fn get(&self, key: &RayObj) -> Option<RayObj> {
+        let pos = ffi::find_index(&self.part(0), key)?;
+        ffi::get_at_index(&self.part(1), pos as i64)
+    }
+
+    /// Check if a key is present.
+    pub fn contains_key(&self, key: &RayObj) -> bool {
+        ffi::find_index(&self.part(0), key).is_some()
+    }
+
+    /// Get the number of key-value pairs.
+    pub fn len(&self) -> usize {
+        self.part(0).len() as usize
+    }
+
+    /// Check if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the map as a [`RayDict`] sharing the same keys and values.
+    pub fn to_dict(&self) -> RayDict {
+        RayDict {
+            ptr: self.ptr.clone(),
+        }
+    }
+
+    /// Get the underlying runtime dict.
+    pub fn ptr(&self) -> &RayObj {
+        &self.ptr
+    }
+
+    /// Get the keys (0) or values (1) list.
+    fn part(&self, idx: i64) -> RayObj {
+        ffi::get_at_index(&self.ptr, idx).unwrap_or_else(ffi::new_list)
+    }
+}
+
+impl Default for RayAnyMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RayAnyMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RayAnyMap[{}]", self.len())
+    }
+}
+
+impl fmt::Display for RayAnyMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ptr)
+    }
+}
@@ -0,0 +1,347 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! CSV ingestion into tables.
+
+use crate::error::{RayforceError, Result};
+use crate::ffi::RayObj;
+use crate::types::{
+    RayDate, RayList, RaySymbol, RayTable, RayTime, RayTimestamp, RayType, RayVector,
+};
+use crate::*;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Options for parsing CSV input.
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// Whether the first record is a header row to skip.
+    pub header: bool,
+    /// Field delimiter.
+    pub delimiter: char,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            header: true,
+            delimiter: ',',
+        }
+    }
+}
+
+impl RayTable {
+    /// Build a table from CSV data using a `(name, type_code)` schema.
+    ///
+    /// The first record is treated as a header. Supported type codes are
+    /// `TYPE_I16`, `TYPE_I32`, `TYPE_I64`, `TYPE_F64`, `TYPE_SYMBOL`,
+    /// `TYPE_C8` (a list of strings), and `TYPE_DATE`, `TYPE_TIME` and
+    /// `TYPE_TIMESTAMP` in the ISO 8601 forms written by
+    /// [`to_csv_writer`](Self::to_csv_writer). Empty cells become the type's
+    /// null.
+    pub fn from_csv_reader<R: Read>(reader: R, schema: &[(&str, i8)]) -> Result<RayTable> {
+        Self::from_csv_reader_with(reader, schema, &CsvOptions::default())
+    }
+
+    /// Build a table from CSV data with explicit parsing options.
+    pub fn from_csv_reader_with<R: Read>(
+        mut reader: R,
+        schema: &[(&str, i8)],
+        options: &CsvOptions,
+    ) -> Result<RayTable> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(|e| RayforceError::IoError(e.to_string()))?;
+
+        let mut records = parse_records(&text, options.delimiter)?;
+        if options.header && !records.is_empty() {
+            records.remove(0);
+        }
+
+        let mut columns = Vec::with_capacity(schema.len());
+        for (pos, (name, type_code)) in schema.iter().enumerate() {
+            let mut cells = Vec::with_capacity(records.len());
+            for (line, record) in records.iter().enumerate() {
+                let cell = record.get(pos).ok_or_else(|| {
+                    RayforceError::ConversionError(format!(
+                        "record {} has {} fields, expected {}",
+                        line + 1,
+                        record.len(),
+                        schema.len()
+                    ))
+                })?;
+                cells.push(cell.as_str());
+            }
+            columns.push((*name, build_column(name, *type_code, &cells)?));
+        }
+
+        RayTable::from_dict(columns)
+    }
+
+    /// Write the table as CSV: a header row of column names, then one line per row.
+    ///
+    /// Rows are written one at a time. Nulls are written as empty cells,
+    /// temporal values as ISO 8601 strings and symbols without the backtick.
+    pub fn to_csv_writer<W: Write>(&self, mut writer: W) -> Result<()> {
+        let names = self.columns()?;
+        let columns = names
+            .iter()
+            .map(|name| self.get_column(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let header: Vec<String> = names.iter().map(|name| quote_field(name)).collect();
+        writeln!(writer, "{}", header.join(","))
+            .map_err(|e| RayforceError::IoError(e.to_string()))?;
+
+        let mut line = Vec::with_capacity(columns.len());
+        for idx in 0..self.len()? {
+            line.clear();
+            for column in &columns {
+                let cell = crate::ffi::get_at_index(column, idx as i64)
+                    .ok_or(RayforceError::NullPointer)?;
+                line.push(quote_field(&format_cell(cell)?));
+            }
+            writeln!(writer, "{}", line.join(","))
+                .map_err(|e| RayforceError::IoError(e.to_string()))?;
+        }
+
+        writer
+            .flush()
+            .map_err(|e| RayforceError::IoError(e.to_string()))
+    }
+
+    /// Build a table from a CSV file using a `(name, type_code)` schema.
+    pub fn from_csv_path<P: AsRef<Path>>(path: P, schema: &[(&str, i8)]) -> Result<RayTable> {
+        let file = std::fs::File::open(path).map_err(|e| RayforceError::IoError(e.to_string()))?;
+        Self::from_csv_reader(std::io::BufReader::new(file), schema)
+    }
+}
+
+/// Split CSV text into records, honouring quoted fields and `""` escapes.
+fn parse_records(text: &str, delimiter: char) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(RayforceError::ConversionError(
+            "unterminated quoted field".into(),
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+fn parse_cell<T: std::str::FromStr>(column: &str, cell: &str, null: T) -> Result<T> {
+    let cell = cell.trim();
+    if cell.is_empty() {
+        return Ok(null);
+    }
+    cell.parse().map_err(|_| {
+        RayforceError::ConversionError(format!("invalid value `{}` in column `{}`", cell, column))
+    })
+}
+
+fn parse_column<T: std::str::FromStr + Copy>(
+    column: &str,
+    cells: &[&str],
+    null: T,
+) -> Result<Vec<T>> {
+    cells
+        .iter()
+        .map(|cell| parse_cell(column, cell, null))
+        .collect()
+}
+
+fn build_column(column: &str, type_code: i8, cells: &[&str]) -> Result<RayObj> {
+    // Null sentinels follow Rayforce's representation: the minimum integer and NaN.
+    match type_code.unsigned_abs() as u32 {
+        TYPE_I16 => Ok(RayObj::from(parse_column(column, cells, i16::MIN)?)),
+        TYPE_I32 => Ok(RayObj::from(parse_column(column, cells, i32::MIN)?)),
+        TYPE_I64 => Ok(RayObj::from(parse_column(column, cells, i64::MIN)?)),
+        TYPE_F64 => Ok(RayObj::from(parse_column(column, cells, f64::NAN)?)),
+        TYPE_SYMBOL => Ok(
+            RayVector::<RaySymbol>::from_iter(cells.iter().map(|c| c.trim()))
+                .ptr()
+                .clone(),
+        ),
+        TYPE_C8 => Ok(RayList::from_iter(cells.iter().map(|c| RayObj::from(*c)))
+            .ptr()
+            .clone()),
+        TYPE_DATE => temporal_column(column, TYPE_DATE, cells, NULL_I32, |cell| {
+            let date = NaiveDate::parse_from_str(cell, "%Y-%m-%d").ok()?;
+            Some(RayDate::from_naive_date(date).days())
+        }),
+        TYPE_TIME => temporal_column(column, TYPE_TIME, cells, NULL_I32, |cell| {
+            let time = NaiveTime::parse_from_str(cell, "%H:%M:%S%.f").ok()?;
+            Some(RayTime::from_naive_time(time).ms())
+        }),
+        TYPE_TIMESTAMP => temporal_column(column, TYPE_TIMESTAMP, cells, NULL_I64, |cell| {
+            let dt = NaiveDateTime::parse_from_str(cell, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+            Some(RayTimestamp::from_naive_datetime(dt).nanos())
+        }),
+        _ => Err(RayforceError::ConversionError(format!(
+            "unsupported CSV column type {} for `{}`",
+            type_code, column
+        ))),
+    }
+}
+
+/// Build a date, time or timestamp vector from cells parsed to raw values.
+fn temporal_column<T: Copy>(
+    column: &str,
+    type_code: u32,
+    cells: &[&str],
+    null: T,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<RayObj> {
+    let values = cells
+        .iter()
+        .map(|cell| {
+            let cell = cell.trim();
+            if cell.is_empty() {
+                return Ok(null);
+            }
+            parse(cell).ok_or_else(|| {
+                RayforceError::ConversionError(format!(
+                    "invalid value `{}` in column `{}`",
+                    cell, column
+                ))
+            })
+        })
+        .collect::<Result<Vec<T>>>()?;
+
+    let vector = crate::ffi::new_vector(type_code as i8, values.len() as i64);
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            values.as_ptr(),
+            crate::ffi::get_obj_raw_ptr(&vector) as *mut T,
+            values.len(),
+        );
+    }
+    Ok(vector)
+}
+
+/// Quote a field if it contains a delimiter, quote or line break.
+fn quote_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn format_cell(cell: RayObj) -> Result<String> {
+    let code = cell.type_code();
+    if code == TYPE_C8 as i8 {
+        return String::try_from(cell);
+    }
+    if code >= 0 {
+        return Ok(cell.to_string());
+    }
+
+    let text = match code.unsigned_abs() as u32 {
+        TYPE_B8 => bool::try_from(cell)?.to_string(),
+        TYPE_I16 => null_or(i16::try_from(cell)?, i16::MIN),
+        TYPE_I32 => null_or(i32::try_from(cell)?, i32::MIN),
+        TYPE_I64 => null_or(i64::try_from(cell)?, i64::MIN),
+        TYPE_F64 => {
+            let v = f64::try_from(cell)?;
+            if v.is_nan() {
+                String::new()
+            } else {
+                v.to_string()
+            }
+        }
+        TYPE_SYMBOL => String::try_from(cell)?,
+        // Temporal nulls are checked on the raw value: converting them to
+        // chrono would overflow or print a made-up instant.
+        TYPE_DATE => {
+            let date = RayDate::from_ptr(cell)?;
+            if date.days() == NULL_I32 {
+                String::new()
+            } else {
+                date.to_naive_date().to_string()
+            }
+        }
+        TYPE_TIME => {
+            let time = RayTime::from_ptr(cell)?;
+            if time.ms() == NULL_I32 {
+                String::new()
+            } else {
+                time.to_naive_time().format("%H:%M:%S%.3f").to_string()
+            }
+        }
+        TYPE_TIMESTAMP => {
+            let ts = RayTimestamp::from_ptr(cell)?;
+            if ts.nanos() == NULL_I64 {
+                String::new()
+            } else {
+                ts.to_naive_datetime().format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+            }
+        }
+        _ => cell.to_string(),
+    };
+    Ok(text)
+}
+
+fn null_or<T: PartialEq + ToString>(value: T, null: T) -> String {
+    if value == null {
+        String::new()
+    } else {
+        value.to_string()
+    }
+}
@@ -30,11 +30,19 @@ mod scalars;
 mod containers;
 pub mod table;
 mod operators;
+mod csv;
+mod value;
+#[cfg(feature = "arrow")]
+mod arrow_interop;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 pub use scalars::*;
 pub use containers::*;
 pub use table::*;
 pub use operators::*;
+pub use csv::CsvOptions;
+pub use value::RayValue;
 
 use crate::error::{RayforceError, Result};
 use crate::ffi::RayObj;
@@ -44,6 +44,7 @@ pub enum Operation {
     GreaterEqual,
     LessThan,
     LessEqual,
+    Match,
 
     // Logical
     And,
@@ -76,11 +77,13 @@ pub enum Operation {
     // Query
     Select,
     Insert,
+    Delete,
     Where,
 
     // Join
     InnerJoin,
     LeftJoin,
+    AsofJoin,
     WindowJoin,
     WindowJoin1,
 
@@ -92,6 +95,10 @@ pub enum Operation {
     IAsc,
     IDesc,
 
+    // Schema
+    XCol,
+    XKey,
+
     // Accessor
     At,
 
@@ -101,9 +108,12 @@ pub enum Operation {
 
     // Composition
     Til,
+    Take,
+    Drop,
 
     // Type
     ListOp,
+    IsNull,
 
     // Other
     Eval,
@@ -132,6 +142,7 @@ impl Operation {
             Operation::GreaterEqual => ">=",
             Operation::LessThan => "<",
             Operation::LessEqual => "<=",
+            Operation::Match => "~",
             Operation::And => "and",
             Operation::Or => "or",
             Operation::Not => "not",
@@ -152,9 +163,11 @@ impl Operation {
             Operation::Distinct => "distinct",
             Operation::Select => "select",
             Operation::Insert => "insert",
+            Operation::Delete => "delete",
             Operation::Where => "where",
             Operation::InnerJoin => "inner-join",
             Operation::LeftJoin => "left-join",
+            Operation::AsofJoin => "asof-join",
             Operation::WindowJoin => "window-join",
             Operation::WindowJoin1 => "window-join1",
             Operation::Asc => "asc",
@@ -163,11 +176,16 @@ impl Operation {
             Operation::XDesc => "xdesc",
             Operation::IAsc => "iasc",
             Operation::IDesc => "idesc",
+            Operation::XCol => "xcol",
+            Operation::XKey => "xkey",
             Operation::At => "at",
             Operation::Map => "map",
             Operation::MapLeft => "map-left",
             Operation::Til => "til",
+            Operation::Take => "take",
+            Operation::Drop => "drop",
             Operation::ListOp => "list",
+            Operation::IsNull => "null",
             Operation::Eval => "eval",
             Operation::Quote => "quote",
             Operation::Concat => "concat",
@@ -31,6 +31,15 @@ use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use std::fmt;
 use uuid::Uuid;
 
+/// Offset a day or millisecond count, keeping null null and saturating
+/// short of the null value at the bottom of the range.
+fn offset_i32(value: i32, n: i32) -> i32 {
+    if value == NULL_I32 {
+        return NULL_I32;
+    }
+    value.saturating_add(n).max(NULL_I32 + 1)
+}
+
 /// Boolean type.
 #[derive(Clone)]
 pub struct RayBool {
@@ -226,6 +235,11 @@ impl RayI16 {
     pub fn value(&self) -> i16 {
         unsafe { *(*self.ptr.as_ptr()).__bindgen_anon_1.i16_.as_ref() }
     }
+
+    /// Create the null i16 (`NULL_I16`).
+    pub fn null() -> Self {
+        Self::new(NULL_I16)
+    }
 }
 
 impl RayType for RayI16 {
@@ -280,6 +294,11 @@ impl RayI32 {
     pub fn value(&self) -> i32 {
         unsafe { *(*self.ptr.as_ptr()).__bindgen_anon_1.i32_.as_ref() }
     }
+
+    /// Create the null i32 (`NULL_I32`).
+    pub fn null() -> Self {
+        Self::new(NULL_I32)
+    }
 }
 
 impl RayType for RayI32 {
@@ -334,6 +353,11 @@ impl RayI64 {
     pub fn value(&self) -> i64 {
         unsafe { *(*self.ptr.as_ptr()).__bindgen_anon_1.i64_.as_ref() }
     }
+
+    /// Create the null i64 (`NULL_I64`).
+    pub fn null() -> Self {
+        Self::new(NULL_I64)
+    }
 }
 
 impl RayType for RayI64 {
@@ -400,6 +424,26 @@ impl RayF64 {
     pub fn value(&self) -> f64 {
         unsafe { *(*self.ptr.as_ptr()).__bindgen_anon_1.f64_.as_ref() }
     }
+
+    /// Create the null f64 (NaN).
+    pub fn null() -> Self {
+        Self::new(NULL_F64)
+    }
+
+    /// Create positive infinity (`INF_F64`).
+    pub fn inf() -> Self {
+        Self::new(INF_F64)
+    }
+
+    /// Create negative infinity.
+    pub fn neg_inf() -> Self {
+        Self::new(-INF_F64)
+    }
+
+    /// Check if the value is positive or negative infinity.
+    pub fn is_inf(&self) -> bool {
+        self.value().is_infinite()
+    }
 }
 
 impl RayType for RayF64 {
@@ -466,6 +510,37 @@ impl RaySymbol {
     pub fn value(&self) -> String {
         crate::ffi::symbol_to_string(&self.ptr).unwrap_or_default()
     }
+
+    /// Get the interned id of the symbol.
+    ///
+    /// Ids are stable for the lifetime of the runtime, so they can be cached
+    /// and turned back into symbols with [`RaySymbol::from_id`].
+    pub fn id(&self) -> i64 {
+        unsafe { *(*self.ptr.as_ptr()).__bindgen_anon_1.i64_.as_ref() }
+    }
+
+    /// Rebuild a symbol from an interned id without re-interning its string.
+    ///
+    /// Returns `None` if no symbol has that id.
+    pub fn from_id(id: i64) -> Option<Self> {
+        unsafe {
+            if str_from_symbol(id).is_null() {
+                return None;
+            }
+            let obj = symboli64(id);
+            if obj.is_null() {
+                return None;
+            }
+            Some(Self {
+                ptr: RayObj::from_raw(obj),
+            })
+        }
+    }
+
+    /// Create the null symbol (the empty symbol).
+    pub fn null() -> Self {
+        Self::new("")
+    }
 }
 
 impl RayType for RaySymbol {
@@ -487,6 +562,22 @@ impl RayType for RaySymbol {
     }
 }
 
+/// Symbols are interned, so equality compares interned ids:
+/// two `RaySymbol::new("x")` are equal.
+impl PartialEq for RaySymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for RaySymbol {}
+
+impl std::hash::Hash for RaySymbol {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
 impl fmt::Debug for RaySymbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "RaySymbol(`{})", self.value())
@@ -586,6 +677,26 @@ impl RayDate {
         let epoch = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
         epoch + chrono::Duration::days(self.days() as i64)
     }
+
+    /// Parse a date in `YYYY-MM-DD` form.
+    pub fn parse(s: &str) -> Result<Self> {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(Self::from_naive_date)
+            .map_err(|e| RayforceError::ConversionError(format!("invalid date `{}`: {}", s, e)))
+    }
+
+    /// Get the date `n` days later (earlier if `n` is negative).
+    ///
+    /// The null date stays null; other dates saturate at the ends of the
+    /// representable range instead of overflowing into the null value.
+    pub fn add_days(&self, n: i32) -> RayDate {
+        RayDate::from_days(offset_i32(self.days(), n))
+    }
+
+    /// Create the null date.
+    pub fn null() -> Self {
+        Self::from_days(NULL_I32)
+    }
 }
 
 impl RayType for RayDate {
@@ -625,6 +736,14 @@ impl From<NaiveDate> for RayDate {
     }
 }
 
+impl TryFrom<RayObj> for NaiveDate {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        RayDate::from_ptr(obj).map(|v| v.to_naive_date())
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type Date = RayDate;
 
@@ -662,6 +781,26 @@ impl RayTime {
         NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
             .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap())
     }
+
+    /// Parse a time in `HH:MM:SS` or `HH:MM:SS.fff` form.
+    pub fn parse(s: &str) -> Result<Self> {
+        NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+            .map(Self::from_naive_time)
+            .map_err(|e| RayforceError::ConversionError(format!("invalid time `{}`: {}", s, e)))
+    }
+
+    /// Get the time `n` milliseconds later (earlier if `n` is negative).
+    ///
+    /// The result is not wrapped at midnight. The null time stays null;
+    /// other times saturate like [`RayDate::add_days`].
+    pub fn add_ms(&self, n: i32) -> RayTime {
+        RayTime::from_ms(offset_i32(self.ms(), n))
+    }
+
+    /// Create the null time.
+    pub fn null() -> Self {
+        Self::from_ms(NULL_I32)
+    }
 }
 
 impl RayType for RayTime {
@@ -701,6 +840,14 @@ impl From<NaiveTime> for RayTime {
     }
 }
 
+impl TryFrom<RayObj> for NaiveTime {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        RayTime::from_ptr(obj).map(|v| v.to_naive_time())
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type Time = RayTime;
 
@@ -738,6 +885,55 @@ impl RayTimestamp {
             .map(|dt| dt.naive_utc())
             .unwrap_or_default()
     }
+
+    /// Parse an RFC 3339 timestamp, e.g. `2024-01-15T09:30:00.5+01:00`.
+    ///
+    /// The offset is applied, so the stored value is in UTC.
+    pub fn parse(s: &str) -> Result<Self> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|dt| Self::from_naive_datetime(dt.naive_utc()))
+            .map_err(|e| {
+                RayforceError::ConversionError(format!("invalid timestamp `{}`: {}", s, e))
+            })
+    }
+
+    /// Get the timestamp `n` nanoseconds later (earlier if `n` is negative).
+    ///
+    /// The null timestamp stays null; other timestamps saturate like
+    /// [`RayDate::add_days`].
+    pub fn add_nanos(&self, n: i64) -> RayTimestamp {
+        let nanos = self.nanos();
+        if nanos == NULL_I64 {
+            return RayTimestamp::null();
+        }
+        RayTimestamp::from_nanos(nanos.saturating_add(n).max(NULL_I64 + 1))
+    }
+
+    /// Create the null timestamp.
+    pub fn null() -> Self {
+        Self::from_nanos(NULL_I64)
+    }
+}
+
+/// The elapsed time between two timestamps, or `None` if either is null or
+/// the difference does not fit in an i64 of nanoseconds.
+impl std::ops::Sub for &RayTimestamp {
+    type Output = Option<chrono::Duration>;
+
+    fn sub(self, rhs: &RayTimestamp) -> Option<chrono::Duration> {
+        if self.nanos() == NULL_I64 || rhs.nanos() == NULL_I64 {
+            return None;
+        }
+        self.nanos().checked_sub(rhs.nanos()).map(chrono::Duration::nanoseconds)
+    }
+}
+
+impl std::ops::Sub for RayTimestamp {
+    type Output = Option<chrono::Duration>;
+
+    fn sub(self, rhs: RayTimestamp) -> Option<chrono::Duration> {
+        &self - &rhs
+    }
 }
 
 impl RayType for RayTimestamp {
@@ -777,6 +973,14 @@ impl From<NaiveDateTime> for RayTimestamp {
     }
 }
 
+impl TryFrom<RayObj> for NaiveDateTime {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        RayTimestamp::from_ptr(obj).map(|v| v.to_naive_datetime())
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type Timestamp = RayTimestamp;
 
@@ -825,6 +1029,11 @@ impl RayGuid {
             Uuid::from_bytes(bytes)
         }
     }
+
+    /// Create the null GUID (all zeros).
+    pub fn null() -> Self {
+        Self::new(Uuid::nil())
+    }
 }
 
 impl RayType for RayGuid {
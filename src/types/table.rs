@@ -27,7 +27,7 @@ use crate::error::{RayforceError, Result};
 use crate::ffi::{self, RayObj};
 use crate::types::{RayDict, RayList, RayType, RaySymbol, RayVector};
 use crate::*;
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 
 /// A Rayforce table.
@@ -71,6 +71,38 @@ impl RayTable {
         })
     }
 
+    /// Create a table with typed columns of `rows` zeroed elements.
+    ///
+    /// Each column is `(name, type_code)` with a vector type code such as
+    /// `TYPE_I32 as i8`; `TYPE_C8` makes a list of empty strings. Cells start
+    /// as zero (`0`, `0.0`, `false`, 2000-01-01, ...) and can be filled with
+    /// [`set_cell`](Self::set_cell).
+    pub fn from_columns(cols: &[(&str, i8)], rows: usize) -> Result<Self> {
+        let mut columns = Vec::with_capacity(cols.len());
+        for (i, &(name, type_code)) in cols.iter().enumerate() {
+            if cols[..i].iter().any(|(prev, _)| *prev == name) {
+                return Err(RayforceError::QueryError(format!("duplicate column `{}`", name)));
+            }
+            let column = if type_code == TYPE_C8 as i8 {
+                RayList::from_iter((0..rows).map(|_| RayObj::from(""))).ptr().clone()
+            } else {
+                let size = ffi::element_size(type_code).ok_or_else(|| {
+                    RayforceError::ConversionError(format!(
+                        "unsupported type code {} for column `{}`",
+                        type_code, name
+                    ))
+                })?;
+                let column = ffi::new_vector(type_code, rows as i64);
+                unsafe {
+                    std::ptr::write_bytes(ffi::get_obj_raw_ptr(&column), 0, rows * size);
+                }
+                column
+            };
+            columns.push((name, column));
+        }
+        RayTable::from_dict(columns)
+    }
+
     /// Create a table reference by name (lazy loading).
     pub fn from_name(name: &str) -> Self {
         Self {
@@ -81,8 +113,11 @@ impl RayTable {
     }
 
     /// Create from a RayObj pointer.
+    ///
+    /// Accepts a table or a keyed table, i.e. a dict from a key table to a
+    /// value table as returned by `xkey`.
     pub fn from_ptr(ptr: RayObj) -> Result<Self> {
-        if ptr.type_code() != TYPE_TABLE as i8 {
+        if ptr.type_code() != TYPE_TABLE as i8 && keyed_parts(&ptr).is_none() {
             return Err(RayforceError::TypeMismatch {
                 expected: "RayTable".into(),
                 actual: format!("type code {}", ptr.type_code()),
@@ -105,19 +140,81 @@ impl RayTable {
         self.is_parted
     }
 
+    /// Check if this table has key columns (see [`RayTable::set_key`]).
+    ///
+    /// Keyedness is read from the runtime object, so it survives `eval`,
+    /// `save`/`from_name` and IPC. A reference table is evaluated first.
+    pub fn is_keyed(&self) -> bool {
+        self.resolve().is_ok_and(|table| keyed_parts(&table.ptr).is_some())
+    }
+
+    /// Get the names of the key columns, in key order.
+    ///
+    /// Empty for a table without keys.
+    pub fn key_columns(&self) -> Result<Vec<String>> {
+        match keyed_parts(&self.resolve()?.ptr) {
+            Some((keys, _)) => keys.columns(),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Return a new table keyed by `key_cols`, using the runtime's `xkey`.
+    ///
+    /// The result is the keyed table `xkey` returns, with the key columns
+    /// listed first, in the given order. Upserts built with
+    /// [`RayTable::upsert`] then match rows on them.
+    pub fn set_key(&self, key_cols: &[&str]) -> Result<RayTable> {
+        for (i, key) in key_cols.iter().enumerate() {
+            if key_cols[..i].contains(key) {
+                return Err(RayforceError::QueryError(format!("duplicate key column `{}`", key)));
+            }
+        }
+        let table = self.flat()?;
+        let names = table.columns()?;
+        if let Some(missing) = key_cols.iter().find(|key| !names.iter().any(|name| name == *key)) {
+            return Err(RayforceError::KeyNotFound(missing.to_string()));
+        }
+
+        let keys = RayVector::<RaySymbol>::from_iter(key_cols.iter().copied());
+        let keyed = ffi::call_internal(Operation::XKey.name(), &[keys.ptr(), &table.ptr])?;
+        if keyed_parts(&keyed).is_none() {
+            return Err(RayforceError::TypeMismatch {
+                expected: "keyed table".into(),
+                actual: crate::types::type_name_for_code(keyed.type_code()).into(),
+            });
+        }
+        RayTable::from_ptr(keyed)
+    }
+
+    /// Get a plain table with the same columns, evaluating a reference and
+    /// putting the key columns of a keyed table first.
+    ///
+    /// The columns are shared with `self`, not copied.
+    fn flat(&self) -> Result<RayTable> {
+        let table = self.resolve()?;
+        let Some((keys, values)) = keyed_parts(&table.ptr) else {
+            return Ok(table);
+        };
+
+        let mut columns = Vec::new();
+        for part in [&keys, &values] {
+            for name in part.columns()? {
+                let column = part.get_column(&name)?;
+                columns.push((name, column));
+            }
+        }
+        RayTable::from_dict(columns)
+    }
+
     /// Get the column names.
+    ///
+    /// A keyed table lists its key columns first.
     pub fn columns(&self) -> Result<Vec<String>> {
+        if self.is_reference || keyed_parts(&self.ptr).is_some() {
+            return self.flat()?.columns();
+        }
         unsafe {
-            let ptr = if self.is_reference {
-                // Evaluate the reference to get the actual table
-                let evaled = eval_obj(clone_obj(self.ptr.as_ptr()));
-                if evaled.is_null() {
-                    return Err(RayforceError::EvalFailed("Failed to evaluate table reference".into()));
-                }
-                evaled
-            } else {
-                self.ptr.as_ptr()
-            };
+            let ptr = self.ptr.as_ptr();
 
             // Get the keys (column names) from the table
             // Table structure: [keys, values]
@@ -140,33 +237,19 @@ impl RayTable {
                 }
             }
 
-            if self.is_reference {
-                drop_obj(ptr);
-            }
-
             Ok(result)
         }
     }
 
     /// Get the number of rows.
     pub fn len(&self) -> Result<usize> {
+        if self.is_reference || keyed_parts(&self.ptr).is_some() {
+            return self.flat()?.len();
+        }
         unsafe {
-            let ptr = if self.is_reference {
-                let evaled = eval_obj(clone_obj(self.ptr.as_ptr()));
-                if evaled.is_null() {
-                    return Err(RayforceError::EvalFailed("Failed to evaluate table reference".into()));
-                }
-                evaled
-            } else {
-                self.ptr.as_ptr()
-            };
-
             // Get the values (columns) from the table
-            let values = at_idx(ptr, 1);
+            let values = at_idx(self.ptr.as_ptr(), 1);
             if values.is_null() {
-                if self.is_reference {
-                    drop_obj(ptr);
-                }
                 return Ok(0);
             }
 
@@ -179,10 +262,6 @@ impl RayTable {
                 ffi::get_obj_len(&first_col_obj) as usize
             };
 
-            if self.is_reference {
-                drop_obj(ptr);
-            }
-
             Ok(len)
         }
     }
@@ -194,34 +273,105 @@ impl RayTable {
 
     /// Get a column by name.
     pub fn get_column(&self, name: &str) -> Result<RayObj> {
+        if self.is_reference || keyed_parts(&self.ptr).is_some() {
+            return self.flat()?.get_column(name);
+        }
         let key = ffi::new_symbol(name);
         unsafe {
-            let ptr = if self.is_reference {
-                let evaled = eval_obj(clone_obj(self.ptr.as_ptr()));
-                if evaled.is_null() {
-                    return Err(RayforceError::EvalFailed("Failed to evaluate table reference".into()));
-                }
-                evaled
-            } else {
-                self.ptr.as_ptr()
-            };
-
-            let col = at_obj(ptr, key.as_ptr());
+            let col = at_obj(self.ptr.as_ptr(), key.as_ptr());
             if col.is_null() {
-                if self.is_reference {
-                    drop_obj(ptr);
-                }
                 return Err(RayforceError::KeyNotFound(name.to_string()));
             }
+            Ok(RayObj::from_raw(clone_obj(col)))
+        }
+    }
 
-            let result = RayObj::from_raw(clone_obj(col));
+    /// Get the value in column `col` at `row`.
+    ///
+    /// Errors with `KeyNotFound` for an unknown column and `IndexOutOfBounds`
+    /// for a row past the end.
+    pub fn get_cell(&self, col: &str, row: usize) -> Result<RayObj> {
+        let column = self.get_column(col)?;
+        let len = ffi::get_obj_len(&column) as usize;
+        if row >= len {
+            return Err(RayforceError::IndexOutOfBounds {
+                index: row as i64,
+                length: len as i64,
+            });
+        }
+        ffi::get_at_index(&column, row as i64).ok_or(RayforceError::NullPointer)
+    }
 
-            if self.is_reference {
-                drop_obj(ptr);
-            }
+    /// Set the value in column `col` at `row`.
+    ///
+    /// The value must be an atom of the column's element type; a list column
+    /// accepts any value. The column is copied before the write, so clones of
+    /// this table are unaffected. Each call copies one column, so for bulk
+    /// changes prefer [`update`](Self::update). Named table references cannot
+    /// be modified this way.
+    pub fn set_cell(&mut self, col: &str, row: usize, value: impl Into<RayObj>) -> Result<()> {
+        if self.is_reference {
+            return Err(RayforceError::QueryError(
+                "set_cell needs a concrete table; use update() for a named table".into(),
+            ));
+        }
+        let value = value.into();
+        let mut column = self.get_column(col)?;
+        let len = ffi::get_obj_len(&column) as usize;
+        if row >= len {
+            return Err(RayforceError::IndexOutOfBounds {
+                index: row as i64,
+                length: len as i64,
+            });
+        }
+        let column_type = column.type_code();
+        if column_type != TYPE_LIST as i8 && value.type_code() != -column_type {
+            return Err(RayforceError::TypeMismatch {
+                expected: crate::types::type_name_for_code(column_type).into(),
+                actual: crate::types::type_name_for_code(value.type_code()).into(),
+            });
+        }
 
-            Ok(result)
+        column.make_unique();
+        ffi::insert_at_index(&mut column, row as i64, value);
+        let keys = self.key_columns()?;
+        *self = self.with_column(col, column)?;
+        if !keys.is_empty() {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            *self = self.set_key(&keys)?;
         }
+        Ok(())
+    }
+
+    /// Append one row to a named table, with values in column order.
+    ///
+    /// The row goes straight to the runtime's `insert` as a list, without
+    /// building a dict or reading the table first, which makes this suited to
+    /// ingest loops. A row with the wrong number of values, or a value that
+    /// does not fit its column, is rejected by `insert` and reported as
+    /// `QueryError`. Only table references (see [`from_name`](Self::from_name))
+    /// can be appended to.
+    pub fn append_row(&self, values: &[RayObj]) -> Result<()> {
+        if !self.is_reference {
+            return Err(RayforceError::QueryError(
+                "append_row needs a named table reference".into(),
+            ));
+        }
+
+        let row = RayList::from_iter(values.iter().cloned());
+        let name = ffi::quote(&self.ptr);
+        unsafe {
+            let args = [name.as_ptr(), row.ptr().as_ptr()];
+            let result = ray_insert(args.as_ptr() as *mut *mut obj_t, 2);
+            if result.is_null() {
+                return Err(RayforceError::QueryError("Insert query failed".into()));
+            }
+            let result = RayObj::from_raw(result);
+            if result.is_error() {
+                return Err(RayforceError::QueryError(ffi::get_error_message(result.as_ptr())));
+            }
+        }
+        Ok(())
     }
 
     /// Save the table to the environment with a name.
@@ -250,11 +400,20 @@ impl RayTable {
         RayInsertQuery::new(self.clone())
     }
 
-    /// Create an upsert query builder.
+    /// Create an upsert query builder matching rows on the first
+    /// `match_by_first` columns.
+    ///
+    /// A keyed table (see [`RayTable::set_key`]) always matches on its key
+    /// columns, and `match_by_first` is ignored.
     pub fn upsert(&self, match_by_first: usize) -> RayUpsertQuery {
         RayUpsertQuery::new(self.clone(), match_by_first)
     }
 
+    /// Create a delete query builder.
+    pub fn delete(&self) -> RayDeleteQuery {
+        RayDeleteQuery::new(self.clone())
+    }
+
     /// Sort ascending by columns.
     pub fn xasc(&self, columns: &[&str]) -> Result<RayTable> {
         let col_syms = RayVector::<RaySymbol>::from_iter(columns.iter().copied());
@@ -307,6 +466,12 @@ impl RayTable {
         }
     }
 
+    fn take_rows(&self, n: usize) -> Result<RayTable> {
+        let count = RayObj::from(n as i64);
+        let result = ffi::call_internal(Operation::Take.name(), &[&count, &self.ptr])?;
+        RayTable::from_ptr(result)
+    }
+
     /// Inner join with another table.
     pub fn inner_join(&self, other: &RayTable, on: &[&str]) -> Result<RayTable> {
         self.join_impl(other, on, "inner-join")
@@ -317,6 +482,46 @@ impl RayTable {
         self.join_impl(other, on, "left-join")
     }
 
+    /// As-of join with another table.
+    ///
+    /// All but the last `on` column must match exactly; the last one is the time
+    /// column, and each row picks up the most recent prior row from `other`.
+    pub fn asof_join(&self, other: &RayTable, on: &[&str]) -> Result<RayTable> {
+        self.join_impl(other, on, Operation::AsofJoin.name())
+    }
+
+    /// Window join with another table.
+    ///
+    /// For each row, collects the rows of `other` that match the leading `on`
+    /// columns and whose time (the last `on` column) lies within
+    /// `[time + window.0, time + window.1]`. Both ends of the window are inclusive.
+    pub fn window_join(&self, other: &RayTable, on: &[&str], window: (i64, i64)) -> Result<RayTable> {
+        let join_type = Operation::WindowJoin.name();
+        let on_syms = RayVector::<RaySymbol>::from_iter(on.iter().copied());
+        let bounds = RayVector::<i64>::from_slice(&[window.0, window.1]);
+        let mut args = RayList::new();
+        args.push(ffi::get_internal_function(join_type).ok_or_else(|| {
+            RayforceError::CApiError(format!("{} not found", join_type))
+        })?);
+        args.push(on_syms.ptr().clone());
+        args.push(self.ptr.clone());
+        args.push(other.ptr.clone());
+        args.push(bounds.ptr().clone());
+
+        unsafe {
+            let result = eval_obj(clone_obj(args.ptr().as_ptr()));
+            if result.is_null() {
+                return Err(RayforceError::EvalFailed(format!("{} failed", join_type)));
+            }
+            if (*result).type_ == TYPE_ERR as i8 {
+                let msg = ffi::get_error_message(result);
+                drop_obj(result);
+                return Err(RayforceError::QueryError(msg));
+            }
+            RayTable::from_ptr(RayObj::from_raw(result))
+        }
+    }
+
     fn join_impl(&self, other: &RayTable, on: &[&str], join_type: &str) -> Result<RayTable> {
         let on_syms = RayVector::<RaySymbol>::from_iter(on.iter().copied());
         let mut args = RayList::new();
@@ -353,6 +558,343 @@ impl RayTable {
             RayTable::from_ptr(RayObj::from_raw(result))
         }
     }
+
+    /// Check whether two tables are structurally equal, using the runtime's
+    /// match operator (`~`).
+    ///
+    /// Column names, order, types and every cell must agree. Reference
+    /// tables are evaluated first.
+    pub fn equals(&self, other: &RayTable) -> Result<bool> {
+        let (left, right) = (self.resolve()?, other.resolve()?);
+        left.ptr.deep_equals(&right.ptr)
+    }
+
+    /// Get the rows present in one table but not the other.
+    ///
+    /// The result holds the rows of `self` missing from `other`, followed by
+    /// the rows of `other` missing from `self`. Rows are compared by value,
+    /// by presence rather than count. Both tables must have the same
+    /// columns in the same order, otherwise `QueryError` is returned.
+    pub fn diff(&self, other: &RayTable) -> Result<RayTable> {
+        let (left, right) = (self.resolve()?, other.resolve()?);
+        let names = left.columns()?;
+        if names != right.columns()? {
+            return Err(RayforceError::QueryError(
+                "cannot diff tables with different columns".into(),
+            ));
+        }
+
+        let left_keys = left.row_keys(&names)?;
+        let right_keys = right.row_keys(&names)?;
+        let left_set: HashSet<&[u8]> = left_keys.iter().map(Vec::as_slice).collect();
+        let right_set: HashSet<&[u8]> = right_keys.iter().map(Vec::as_slice).collect();
+
+        let missing = |keys: &[Vec<u8>], set: &HashSet<&[u8]>| -> Vec<i64> {
+            (0..keys.len() as i64)
+                .filter(|&i| !set.contains(keys[i as usize].as_slice()))
+                .collect()
+        };
+        let only_left = left.take_indices(&names, &missing(&left_keys, &right_set))?;
+        let only_right = right.take_indices(&names, &missing(&right_keys, &left_set))?;
+        only_left.concat(&only_right)
+    }
+
+    /// Serialize each row's cells, giving a byte key that compares rows by value.
+    fn row_keys(&self, names: &[String]) -> Result<Vec<Vec<u8>>> {
+        let values = names
+            .iter()
+            .map(|name| self.get_column(name))
+            .collect::<Result<Vec<_>>>()?;
+        (0..self.len()? as i64)
+            .map(|idx| {
+                let mut row = RayList::new();
+                for column in &values {
+                    row.push(ffi::get_at_index(column, idx).ok_or(RayforceError::NullPointer)?);
+                }
+                row.ptr().serialize()
+            })
+            .collect()
+    }
+
+    /// Build a table from the rows at `indices`, in that order.
+    fn take_indices(&self, names: &[String], indices: &[i64]) -> Result<RayTable> {
+        let indices = RayObj::from(indices);
+        let columns = names
+            .iter()
+            .map(|name| {
+                let column = self.get_column(name)?;
+                let taken = ffi::call_internal(Operation::At.name(), &[&column, &indices])?;
+                Ok((name.as_str(), taken))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RayTable::from_dict(columns)
+    }
+
+    /// Turn the table back into a dictionary of columns, like `(flip t)`.
+    ///
+    /// A reference table is evaluated first. The dictionary shares the
+    /// table's column objects.
+    pub fn flip(&self) -> Result<RayDict> {
+        let table = self.flat()?;
+        let keys = ffi::get_at_index(&table.ptr, 0).ok_or(RayforceError::NullPointer)?;
+        let values = ffi::get_at_index(&table.ptr, 1).ok_or(RayforceError::NullPointer)?;
+        RayDict::new(keys, values)
+    }
+
+    /// Materialize every row as a typed Rust value.
+    ///
+    /// Each row is handed to [`FromRayRow::from_row`], which pulls cells by
+    /// column name or position.
+    pub fn rows<T: FromRayRow>(&self) -> Result<Vec<T>> {
+        let names = self.columns()?;
+        let values = names
+            .iter()
+            .map(|name| self.get_column(name))
+            .collect::<Result<Vec<_>>>()?;
+        let len = self.len()?;
+
+        (0..len)
+            .map(|idx| {
+                T::from_row(&RayRow {
+                    names: &names,
+                    values: &values,
+                    idx,
+                })
+            })
+            .collect()
+    }
+
+    /// Iterate over the rows as dictionaries of column name -> value.
+    ///
+    /// A reference table is evaluated once up front. Rows are built lazily by
+    /// indexing into the column vectors, so the table is never transposed.
+    /// A row whose cells cannot be read is yielded as an error rather than
+    /// skipped.
+    pub fn iter_rows(&self) -> Result<impl Iterator<Item = Result<RayDict>>> {
+        let table = self.resolve()?;
+        let names = table.columns()?;
+        let values = names
+            .iter()
+            .map(|name| table.get_column(name))
+            .collect::<Result<Vec<_>>>()?;
+        let len = table.len()?;
+
+        Ok((0..len).map(move |idx| {
+            let cells = names
+                .iter()
+                .zip(&values)
+                .map(|(name, column)| {
+                    let cell = ffi::get_at_index(column, idx as i64).ok_or_else(|| {
+                        RayforceError::QueryError(format!(
+                            "cannot read row {} of column '{}'",
+                            idx, name
+                        ))
+                    })?;
+                    Ok((name.as_str(), cell))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            RayDict::from_pairs(cells)
+        }))
+    }
+
+    /// Describe the table's columns: name, type and attributes.
+    ///
+    /// A reference table is evaluated first. The information is read from the
+    /// column vectors themselves, so it matches what `meta` reports.
+    pub fn meta(&self) -> Result<Vec<ColumnMeta>> {
+        let table = self.resolve()?;
+        table
+            .columns()?
+            .into_iter()
+            .map(|name| {
+                let column = table.get_column(&name)?;
+                Ok(ColumnMeta {
+                    type_code: column.type_code(),
+                    type_name: crate::types::type_name_for_code(column.type_code()),
+                    attrs: column.attrs(),
+                    name,
+                })
+            })
+            .collect()
+    }
+
+    /// Return a new table with `name` set to `data`.
+    ///
+    /// The column is appended, or replaced in place if it already exists. It
+    /// must have as many rows as the table. The original table, or the named
+    /// table behind a reference, is left unchanged.
+    pub fn with_column(&self, name: &str, data: impl Into<RayObj>) -> Result<RayTable> {
+        let data = data.into();
+        let table = self.resolve()?;
+        let names = table.columns()?;
+        let len = table.len()?;
+        let data_len = ffi::get_obj_len(&data) as usize;
+        if !names.is_empty() && (data.type_code() < 0 || data_len != len) {
+            return Err(RayforceError::QueryError(format!(
+                "column `{}` has length {}, table has {} rows",
+                name, data_len, len
+            )));
+        }
+
+        let mut columns = Vec::with_capacity(names.len() + 1);
+        for existing in &names {
+            let column = if existing == name {
+                data.clone()
+            } else {
+                table.get_column(existing)?
+            };
+            columns.push((existing.as_str(), column));
+        }
+        if !names.iter().any(|existing| existing == name) {
+            columns.push((name, data));
+        }
+        RayTable::from_dict(columns)
+    }
+
+    /// Return a new table without the column `name`.
+    ///
+    /// The original table, or the named table behind a reference, is left
+    /// unchanged.
+    pub fn drop_column(&self, name: &str) -> Result<RayTable> {
+        let table = self.resolve()?;
+        let names = table.columns()?;
+        if !names.iter().any(|existing| existing == name) {
+            return Err(RayforceError::KeyNotFound(name.to_string()));
+        }
+
+        let mut columns = Vec::with_capacity(names.len() - 1);
+        for existing in names.iter().filter(|existing| *existing != name) {
+            columns.push((existing.as_str(), table.get_column(existing)?));
+        }
+        RayTable::from_dict(columns)
+    }
+
+    /// Return a new table with columns renamed according to `(old, new)` pairs.
+    ///
+    /// Column order and data are preserved; columns not mentioned keep their
+    /// names. Errors if an old name is not a column of the table.
+    pub fn rename(&self, mapping: &[(&str, &str)]) -> Result<RayTable> {
+        let table = self.flat()?;
+        let mut names = table.columns()?;
+        for (old, new) in mapping {
+            let slot = names
+                .iter_mut()
+                .find(|name| name == old)
+                .ok_or_else(|| RayforceError::KeyNotFound(old.to_string()))?;
+            *slot = new.to_string();
+        }
+
+        let new_names = RayVector::<RaySymbol>::from_iter(names.iter().map(String::as_str));
+        let mut args = RayList::new();
+        args.push(Operation::XCol.to_ray_obj().ok_or_else(|| {
+            RayforceError::CApiError("xcol not found".into())
+        })?);
+        args.push(new_names.ptr().clone());
+        args.push(table.ptr.clone());
+
+        unsafe {
+            let result = eval_obj(clone_obj(args.ptr().as_ptr()));
+            if result.is_null() {
+                return Err(RayforceError::EvalFailed("xcol failed".into()));
+            }
+            RayTable::from_ptr(RayObj::from_raw(result))
+        }
+    }
+
+    /// Render the table as a text grid limited to `max_width` characters per
+    /// line and `max_rows` data rows.
+    ///
+    /// Columns that do not fit are left out and rows past `max_rows` are cut,
+    /// each noted in a `... N more columns` / `... N more rows` footer.
+    pub fn format_with(&self, max_width: usize, max_rows: usize) -> String {
+        self.render(max_width, max_rows)
+            .unwrap_or_else(|e| format!("<table: {}>", e))
+    }
+
+    fn render(&self, max_width: usize, max_rows: usize) -> Result<String> {
+        let table = self.resolve()?;
+        let names = table.columns()?;
+        let len = table.len()?;
+        let shown_rows = len.min(max_rows);
+
+        // Each column as its header followed by its formatted cells.
+        let mut columns: Vec<Vec<String>> = Vec::with_capacity(names.len());
+        let mut widths: Vec<usize> = Vec::with_capacity(names.len());
+        let mut width = 0;
+        for name in &names {
+            let data = table.get_column(name)?;
+            let mut cells = Vec::with_capacity(shown_rows + 1);
+            cells.push(name.clone());
+            for idx in 0..shown_rows {
+                let cell = ffi::get_at_index(&data, idx as i64)
+                    .map(|obj| obj.format_compact())
+                    .unwrap_or_default();
+                cells.push(cell);
+            }
+
+            let col_width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+            let needed = if columns.is_empty() { col_width } else { width + 2 + col_width };
+            if !columns.is_empty() && needed > max_width {
+                break;
+            }
+            width = needed;
+            columns.push(cells);
+            widths.push(col_width);
+        }
+
+        let mut out = String::new();
+        for line in 0..=shown_rows {
+            let row: Vec<String> = columns
+                .iter()
+                .zip(&widths)
+                .map(|(cells, w)| format!("{:<w$}", cells[line], w = *w))
+                .collect();
+            let row = row.join("  ");
+            out.push_str(&row.trim_end().chars().take(max_width).collect::<String>());
+            out.push('\n');
+            if line == 0 {
+                let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+                out.push_str(&rule.join("  ").chars().take(max_width).collect::<String>());
+                out.push('\n');
+            }
+        }
+
+        if columns.len() < names.len() {
+            out.push_str(&format!("... {} more columns\n", names.len() - columns.len()));
+        }
+        if shown_rows < len {
+            out.push_str(&format!("... {} more rows\n", len - shown_rows));
+        }
+        Ok(out)
+    }
+
+    /// Get the concrete table, evaluating a reference if necessary.
+    fn resolve(&self) -> Result<RayTable> {
+        if !self.is_reference {
+            return Ok(self.clone());
+        }
+        unsafe {
+            let evaled = eval_obj(clone_obj(self.ptr.as_ptr()));
+            if evaled.is_null() {
+                return Err(RayforceError::EvalFailed("Failed to evaluate table reference".into()));
+            }
+            RayTable::from_ptr(RayObj::from_raw(evaled))
+        }
+    }
+}
+
+/// Split a keyed table, a dict from a key table to a value table, into its
+/// two tables. Returns `None` for anything else.
+fn keyed_parts(obj: &RayObj) -> Option<(RayTable, RayTable)> {
+    if obj.type_code() != TYPE_DICT as i8 {
+        return None;
+    }
+    let part = |idx| {
+        ffi::get_at_index(obj, idx)
+            .filter(|part| part.type_code() == TYPE_TABLE as i8)
+            .and_then(|part| RayTable::from_ptr(part).ok())
+    };
+    Some((part(0)?, part(1)?))
 }
 
 impl RayType for RayTable {
@@ -368,6 +910,14 @@ impl RayType for RayTable {
     }
 }
 
+impl TryFrom<RayObj> for RayTable {
+    type Error = RayforceError;
+
+    fn try_from(obj: RayObj) -> Result<Self> {
+        RayTable::from_ptr(obj)
+    }
+}
+
 impl fmt::Debug for RayTable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_reference {
@@ -396,6 +946,111 @@ impl fmt::Display for RayTable {
     }
 }
 
+/// Description of a single table column, as returned by [`RayTable::meta`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMeta {
+    /// Column name.
+    pub name: String,
+    /// Rayforce type code of the column vector.
+    pub type_code: i8,
+    /// Rust-side type name, e.g. `"RayI64"`.
+    pub type_name: &'static str,
+    /// Attribute flags of the column vector.
+    pub attrs: u8,
+}
+
+/// A single row of a table, borrowed while materializing [`RayTable::rows`].
+pub struct RayRow<'a> {
+    names: &'a [String],
+    values: &'a [RayObj],
+    idx: usize,
+}
+
+impl RayRow<'_> {
+    /// Get the row index within the table.
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// Get the number of cells in the row.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the row has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get the cell at the given column position.
+    pub fn get_at(&self, pos: usize) -> Result<RayObj> {
+        let column = self.values.get(pos).ok_or(RayforceError::IndexOutOfBounds {
+            index: pos as i64,
+            length: self.values.len() as i64,
+        })?;
+        ffi::get_at_index(column, self.idx as i64).ok_or(RayforceError::NullPointer)
+    }
+
+    /// Get the cell in the named column.
+    pub fn get(&self, name: &str) -> Result<RayObj> {
+        let pos = self
+            .names
+            .iter()
+            .position(|n| n == name)
+            .ok_or_else(|| RayforceError::KeyNotFound(name.to_string()))?;
+        self.get_at(pos)
+    }
+
+    /// Get the cell at the given column position converted to a Rust type.
+    pub fn get_at_as<T: TryFrom<RayObj, Error = RayforceError>>(&self, pos: usize) -> Result<T> {
+        T::try_from(self.get_at(pos)?)
+    }
+
+    /// Get the cell in the named column converted to a Rust type.
+    pub fn get_as<T: TryFrom<RayObj, Error = RayforceError>>(&self, name: &str) -> Result<T> {
+        T::try_from(self.get(name)?)
+    }
+}
+
+/// Build a Rust value from a single table row.
+///
+/// ```rust,ignore
+/// struct Trade { sym: String, price: f64 }
+///
+/// impl FromRayRow for Trade {
+///     fn from_row(row: &RayRow<'_>) -> Result<Self> {
+///         Ok(Trade { sym: row.get_as("sym")?, price: row.get_as("price")? })
+///     }
+/// }
+///
+/// let trades: Vec<Trade> = table.rows()?;
+/// ```
+pub trait FromRayRow: Sized {
+    /// Convert the row into `Self`.
+    fn from_row(row: &RayRow<'_>) -> Result<Self>;
+}
+
+// Tuples are filled by column position.
+macro_rules! impl_from_ray_row_tuple {
+    ($($ty:ident => $pos:tt),+) => {
+        impl<$($ty),+> FromRayRow for ($($ty,)+)
+        where
+            $($ty: TryFrom<RayObj, Error = RayforceError>),+
+        {
+            fn from_row(row: &RayRow<'_>) -> Result<Self> {
+                Ok(($(row.get_at_as::<$ty>($pos)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_ray_row_tuple!(A => 0);
+impl_from_ray_row_tuple!(A => 0, B => 1);
+impl_from_ray_row_tuple!(A => 0, B => 1, C => 2);
+impl_from_ray_row_tuple!(A => 0, B => 1, C => 2, D => 3);
+impl_from_ray_row_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_from_ray_row_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
 /// A table column reference for use in expressions.
 #[derive(Clone)]
 pub struct RayColumn {
@@ -452,6 +1107,54 @@ impl RayColumn {
         RayExpression::binary(Operation::In, self.clone(), values.into())
     }
 
+    /// Null check.
+    pub fn is_null(&self) -> RayExpression {
+        RayExpression::unary(Operation::IsNull, self.clone())
+    }
+
+    /// Non-null check.
+    pub fn is_not_null(&self) -> RayExpression {
+        self.is_null().not()
+    }
+
+    /// Round down to a multiple of `width`, for bucketing with `group_by_expr`.
+    ///
+    /// `width` is in the column's native units: nanoseconds for timestamps,
+    /// milliseconds for times, days for dates.
+    pub fn xbar(&self, width: i64) -> RayExpression {
+        RayExpression {
+            operation: Operation::XBar,
+            operands: vec![ExprOperand::Value(width.into()), ExprOperand::Column(self.clone())],
+        }
+    }
+
+    // Arithmetic operations
+
+    /// Addition.
+    pub fn add(&self, rhs: impl Into<ExprOperand>) -> RayExpression {
+        RayExpression::arithmetic(Operation::Add, self.clone(), rhs.into())
+    }
+
+    /// Subtraction.
+    pub fn sub(&self, rhs: impl Into<ExprOperand>) -> RayExpression {
+        RayExpression::arithmetic(Operation::Subtract, self.clone(), rhs.into())
+    }
+
+    /// Multiplication.
+    pub fn mul(&self, rhs: impl Into<ExprOperand>) -> RayExpression {
+        RayExpression::arithmetic(Operation::Multiply, self.clone(), rhs.into())
+    }
+
+    /// Division.
+    pub fn div(&self, rhs: impl Into<ExprOperand>) -> RayExpression {
+        RayExpression::arithmetic(Operation::Divide, self.clone(), rhs.into())
+    }
+
+    /// Remainder.
+    pub fn modulo(&self, rhs: impl Into<ExprOperand>) -> RayExpression {
+        RayExpression::arithmetic(Operation::Modulo, self.clone(), rhs.into())
+    }
+
     // Aggregation operations
 
     /// Count aggregation.
@@ -507,6 +1210,27 @@ impl From<RayColumn> for RayObj {
     }
 }
 
+macro_rules! impl_column_ops {
+    ($($trait:ident :: $method:ident => $op:ident),*) => {
+        $(
+            impl<T: Into<ExprOperand>> std::ops::$trait<T> for RayColumn {
+                type Output = RayExpression;
+
+                fn $method(self, rhs: T) -> RayExpression {
+                    RayExpression::arithmetic(Operation::$op, self, rhs.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_column_ops!(
+    Add::add => Add,
+    Sub::sub => Subtract,
+    Mul::mul => Multiply,
+    Div::div => Divide
+);
+
 /// Type alias for backward compatibility.
 pub type Column = RayColumn;
 
@@ -519,13 +1243,56 @@ pub struct RayExpression {
     operands: Vec<ExprOperand>,
 }
 
+/// An operand of a [`RayExpression`]: a column, a literal value or a nested
+/// expression.
 #[derive(Clone)]
-enum ExprOperand {
+pub enum ExprOperand {
+    /// A column reference.
     Column(RayColumn),
+    /// A literal value.
     Value(RayObj),
+    /// A nested expression.
     Expr(Box<RayExpression>),
 }
 
+impl From<RayColumn> for ExprOperand {
+    fn from(col: RayColumn) -> Self {
+        ExprOperand::Column(col)
+    }
+}
+
+impl From<&RayColumn> for ExprOperand {
+    fn from(col: &RayColumn) -> Self {
+        ExprOperand::Column(col.clone())
+    }
+}
+
+impl From<RayExpression> for ExprOperand {
+    fn from(expr: RayExpression) -> Self {
+        ExprOperand::Expr(Box::new(expr))
+    }
+}
+
+impl From<RayObj> for ExprOperand {
+    fn from(value: RayObj) -> Self {
+        ExprOperand::Value(value)
+    }
+}
+
+macro_rules! impl_expr_operand_value {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for ExprOperand {
+                fn from(value: $t) -> Self {
+                    ExprOperand::Value(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_expr_operand_value!(i64, i32, f64);
+
 impl RayExpression {
     fn unary(op: Operation, col: RayColumn) -> Self {
         Self {
@@ -541,6 +1308,13 @@ impl RayExpression {
         }
     }
 
+    fn arithmetic(op: Operation, col: RayColumn, rhs: ExprOperand) -> Self {
+        Self {
+            operation: op,
+            operands: vec![ExprOperand::Column(col), rhs],
+        }
+    }
+
     /// Combine expressions with AND.
     pub fn and(self, other: RayExpression) -> RayExpression {
         RayExpression {
@@ -563,6 +1337,43 @@ impl RayExpression {
         }
     }
 
+    /// Negate the expression with NOT. `!expr` does the same.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> RayExpression {
+        RayExpression {
+            operation: Operation::Not,
+            operands: vec![ExprOperand::Expr(Box::new(self))],
+        }
+    }
+
+    /// Render the Rayforce source the expression compiles to, e.g.
+    /// `(and (> x 5) (< y 10))`.
+    ///
+    /// Literal values are printed with their display format, so the result
+    /// is meant for debugging rather than guaranteed to parse back.
+    pub fn to_query_string(&self) -> String {
+        let mut out = format!("({}", self.operation.name());
+        for operand in &self.operands {
+            out.push(' ');
+            match operand {
+                ExprOperand::Column(col) => out.push_str(&col.name),
+                ExprOperand::Value(val) => out.push_str(&val.to_string()),
+                ExprOperand::Expr(expr) => out.push_str(&expr.to_query_string()),
+            }
+        }
+        out.push(')');
+        out
+    }
+
+    /// Compile the expression once, keeping the result for reuse.
+    pub fn into_compiled(self) -> CompiledExpression {
+        let compiled = self.compile();
+        CompiledExpression {
+            source: self,
+            compiled,
+        }
+    }
+
     /// Compile the expression to a RayObj.
     pub fn compile(&self) -> RayObj {
         let mut list = RayList::new();
@@ -591,16 +1402,110 @@ impl RayExpression {
     }
 }
 
+impl fmt::Debug for ExprOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprOperand::Column(col) => write!(f, "Column({:?})", col.name),
+            ExprOperand::Value(val) => write!(f, "{}", val),
+            ExprOperand::Expr(expr) => fmt::Debug::fmt(expr, f),
+        }
+    }
+}
+
+/// Renders the operator tree, e.g. `And(GreaterThan(Column("x"), 5), ...)`.
+impl fmt::Debug for RayExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}(", self.operation)?;
+        for (i, operand) in self.operands.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", operand)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl std::ops::Not for RayExpression {
+    type Output = RayExpression;
+
+    fn not(self) -> RayExpression {
+        RayExpression::not(self)
+    }
+}
+
 /// Type alias for backward compatibility.
 pub type Expression = RayExpression;
 
+/// A [`RayExpression`] compiled once for reuse across queries.
+///
+/// Created by [`RayExpression::into_compiled`]. Passing it to
+/// [`RaySelectQuery::where_cond`] reuses the compiled object instead of
+/// rebuilding it on every execution.
+#[derive(Clone)]
+pub struct CompiledExpression {
+    source: RayExpression,
+    compiled: RayObj,
+}
+
+impl CompiledExpression {
+    /// Get the expression this was compiled from.
+    pub fn expression(&self) -> &RayExpression {
+        &self.source
+    }
+
+    /// Get the compiled object.
+    pub fn ptr(&self) -> &RayObj {
+        &self.compiled
+    }
+
+    /// Combine conditions with AND, or `None` if there are none.
+    fn all(conditions: &[CompiledExpression]) -> Option<CompiledExpression> {
+        let (first, rest) = conditions.split_first()?;
+        Some(rest.iter().fold(first.clone(), |combined, cond| combined.and(cond)))
+    }
+
+    /// Combine with another compiled expression using AND, without recompiling either side.
+    fn and(&self, other: &CompiledExpression) -> CompiledExpression {
+        let mut list = RayList::new();
+        list.push(
+            Operation::And
+                .to_ray_obj()
+                .unwrap_or_else(|| ffi::new_symbol(Operation::And.name())),
+        );
+        list.push(self.compiled.clone());
+        list.push(other.compiled.clone());
+        CompiledExpression {
+            source: self.source.clone().and(other.source.clone()),
+            compiled: list.ptr().clone(),
+        }
+    }
+}
+
+impl From<RayExpression> for CompiledExpression {
+    fn from(expr: RayExpression) -> Self {
+        expr.into_compiled()
+    }
+}
+
+impl fmt::Debug for CompiledExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.source, f)
+    }
+}
+
 /// Select query builder.
 pub struct RaySelectQuery {
     table: RayTable,
     columns: Vec<String>,
-    computed: HashMap<String, RayExpression>,
-    where_conditions: Vec<RayExpression>,
+    computed: Vec<(String, RayExpression)>,
+    where_conditions: Vec<CompiledExpression>,
+    having: Vec<CompiledExpression>,
     group_by: Vec<String>,
+    group_by_exprs: Vec<(String, RayExpression)>,
+    order_by: Vec<String>,
+    descending: bool,
+    limit: Option<usize>,
 }
 
 impl RaySelectQuery {
@@ -608,41 +1513,95 @@ impl RaySelectQuery {
         Self {
             table,
             columns: Vec::new(),
-            computed: HashMap::new(),
+            computed: Vec::new(),
             where_conditions: Vec::new(),
+            having: Vec::new(),
             group_by: Vec::new(),
+            group_by_exprs: Vec::new(),
+            order_by: Vec::new(),
+            descending: false,
+            limit: None,
         }
     }
 
     /// Select specific columns.
+    ///
+    /// Repeated names are dropped, keeping the first occurrence. Unknown
+    /// names fail with `KeyNotFound` when the query is executed.
     pub fn columns(mut self, cols: &[&str]) -> Self {
-        self.columns = cols.iter().map(|s| s.to_string()).collect();
+        self.columns = dedup_names(cols);
         self
     }
 
     /// Add a computed column.
+    ///
+    /// Computed columns appear in the result in the order they were added;
+    /// reusing a name replaces the earlier expression in place.
     pub fn column_expr(mut self, name: &str, expr: RayExpression) -> Self {
-        self.computed.insert(name.to_string(), expr);
+        set_named(&mut self.computed, name, expr);
         self
     }
 
     /// Add a WHERE condition.
-    pub fn where_cond(mut self, expr: RayExpression) -> Self {
-        self.where_conditions.push(expr);
+    ///
+    /// Accepts a [`RayExpression`] or a [`CompiledExpression`] prepared
+    /// earlier with [`RayExpression::into_compiled`].
+    pub fn where_cond(mut self, expr: impl Into<CompiledExpression>) -> Self {
+        self.where_conditions.push(expr.into());
+        self
+    }
+
+    /// Add a HAVING condition, filtering the grouped and aggregated result.
+    ///
+    /// Column references name result columns, such as aggregates added with
+    /// [`column_expr`](Self::column_expr). The filter runs as a second select
+    /// over the grouped output, before ordering and limit are applied.
+    pub fn having(mut self, expr: impl Into<CompiledExpression>) -> Self {
+        self.having.push(expr.into());
         self
     }
 
     /// Add GROUP BY columns.
+    ///
+    /// Repeated names are dropped, keeping the first occurrence. Unknown
+    /// names fail with `KeyNotFound` when the query is executed.
     pub fn group_by(mut self, cols: &[&str]) -> Self {
-        self.group_by = cols.iter().map(|s| s.to_string()).collect();
+        self.group_by = dedup_names(cols);
+        self
+    }
+
+    /// Group by a computed key, such as a [`RayColumn::xbar`] bucket.
+    pub fn group_by_expr(mut self, name: &str, expr: RayExpression) -> Self {
+        self.group_by_exprs.push((name.to_string(), expr));
+        self
+    }
+
+    /// Sort the result ascending by columns (stable).
+    pub fn order_by(mut self, cols: &[&str]) -> Self {
+        self.order_by = cols.iter().map(|s| s.to_string()).collect();
+        self.descending = false;
+        self
+    }
+
+    /// Sort the result descending by columns (stable).
+    pub fn order_by_desc(mut self, cols: &[&str]) -> Self {
+        self.order_by = cols.iter().map(|s| s.to_string()).collect();
+        self.descending = true;
+        self
+    }
+
+    /// Keep at most `n` rows of the (sorted) result.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.limit = Some(n);
         self
     }
 
     /// Execute the query.
     pub fn execute(self) -> Result<RayTable> {
+        self.validate_columns()?;
         let query_dict = self.build_query_dict()?;
         
-        unsafe {
+        let mut table = unsafe {
             let result = ray_select(query_dict.ptr().as_ptr());
             if result.is_null() {
                 return Err(RayforceError::QueryError("Select query failed".into()));
@@ -652,8 +1611,91 @@ impl RaySelectQuery {
                 drop_obj(result);
                 return Err(RayforceError::QueryError(msg));
             }
-            RayTable::from_ptr(RayObj::from_raw(result))
+            RayTable::from_ptr(RayObj::from_raw(result))?
+        };
+
+        if let Some(having) = CompiledExpression::all(&self.having) {
+            table = table.select().where_cond(having).execute()?;
+        }
+
+        // Ordering and limit are applied to the select result
+        if !self.order_by.is_empty() {
+            let cols: Vec<&str> = self.order_by.iter().map(|s| s.as_str()).collect();
+            table = if self.descending {
+                table.xdesc(&cols)?
+            } else {
+                table.xasc(&cols)?
+            };
         }
+        if let Some(n) = self.limit {
+            let n = n.min(table.len()?);
+            table = table.take_rows(n)?;
+        }
+
+        Ok(table)
+    }
+
+    /// Execute a grouped query and split each result row into its group
+    /// key and its aggregates.
+    ///
+    /// The key dict holds the `group_by` and `group_by_expr` columns; the
+    /// aggregate dict holds every other result column. Returns `QueryError`
+    /// if the query has no grouping.
+    pub fn execute_grouped(self) -> Result<Vec<(RayDict, RayDict)>> {
+        let key_names: Vec<String> = self
+            .group_by
+            .iter()
+            .cloned()
+            .chain(self.group_by_exprs.iter().map(|(name, _)| name.clone()))
+            .collect();
+        if key_names.is_empty() {
+            return Err(RayforceError::QueryError(
+                "execute_grouped requires group_by".into(),
+            ));
+        }
+
+        let table = self.execute()?;
+        let names = table.columns()?;
+        let values = names
+            .iter()
+            .map(|name| table.get_column(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        (0..table.len()? as i64)
+            .map(|idx| {
+                let mut key = Vec::new();
+                let mut aggregates = Vec::new();
+                for (name, column) in names.iter().zip(&values) {
+                    let cell = ffi::get_at_index(column, idx).ok_or(RayforceError::NullPointer)?;
+                    if key_names.contains(name) {
+                        key.push((name.as_str(), cell));
+                    } else {
+                        aggregates.push((name.as_str(), cell));
+                    }
+                }
+                Ok((RayDict::from_pairs(key)?, RayDict::from_pairs(aggregates)?))
+            })
+            .collect()
+    }
+
+    /// Check selected and grouped names against the table's columns.
+    ///
+    /// A reference table is resolved here, at execution, so the check sees
+    /// its current columns.
+    fn validate_columns(&self) -> Result<()> {
+        if self.columns.is_empty() && self.group_by.is_empty() {
+            return Ok(());
+        }
+        let existing = self.table.columns()?;
+        if let Some(missing) = self
+            .columns
+            .iter()
+            .chain(&self.group_by)
+            .find(|c| !existing.contains(c))
+        {
+            return Err(RayforceError::KeyNotFound(missing.clone()));
+        }
+        Ok(())
     }
 
     fn build_query_dict(&self) -> Result<RayDict> {
@@ -677,20 +1719,19 @@ impl RaySelectQuery {
         }
 
         // Add WHERE
-        if !self.where_conditions.is_empty() {
-            let mut combined = self.where_conditions[0].clone();
-            for cond in &self.where_conditions[1..] {
-                combined = combined.and(cond.clone());
-            }
-            pairs.push(("where", combined.compile()));
+        if let Some(combined) = CompiledExpression::all(&self.where_conditions) {
+            pairs.push(("where", combined.compiled));
         }
 
         // Add GROUP BY
-        if !self.group_by.is_empty() {
+        if !self.group_by.is_empty() || !self.group_by_exprs.is_empty() {
             let mut by_dict: Vec<(&str, RayObj)> = Vec::new();
             for col in &self.group_by {
                 by_dict.push((col, ffi::new_symbol(col)));
             }
+            for (name, expr) in &self.group_by_exprs {
+                by_dict.push((name, expr.compile()));
+            }
             let by = RayDict::from_pairs(by_dict)?;
             pairs.push(("by", by.ptr().clone()));
         }
@@ -702,10 +1743,29 @@ impl RaySelectQuery {
 /// Type alias for backward compatibility.
 pub type SelectQuery = RaySelectQuery;
 
+/// Add a named expression, replacing an existing one of the same name in place.
+fn set_named(exprs: &mut Vec<(String, RayExpression)>, name: &str, expr: RayExpression) {
+    match exprs.iter_mut().find(|(existing, _)| existing == name) {
+        Some((_, slot)) => *slot = expr,
+        None => exprs.push((name.to_string(), expr)),
+    }
+}
+
+/// Collect column names, dropping exact repeats and keeping the first occurrence.
+fn dedup_names(cols: &[&str]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(cols.len());
+    for col in cols {
+        if !names.iter().any(|name| name == col) {
+            names.push(col.to_string());
+        }
+    }
+    names
+}
+
 /// Update query builder.
 pub struct RayUpdateQuery {
     table: RayTable,
-    updates: HashMap<String, RayExpression>,
+    updates: Vec<(String, RayExpression)>,
     where_conditions: Vec<RayExpression>,
 }
 
@@ -713,24 +1773,25 @@ impl RayUpdateQuery {
     fn new(table: RayTable) -> Self {
         Self {
             table,
-            updates: HashMap::new(),
+            updates: Vec::new(),
             where_conditions: Vec::new(),
         }
     }
 
     /// Set a column to an expression.
     pub fn set(mut self, column: &str, expr: RayExpression) -> Self {
-        self.updates.insert(column.to_string(), expr);
+        set_named(&mut self.updates, column, expr);
         self
     }
 
     /// Set a column to a value.
     pub fn set_value<T: Into<RayObj>>(mut self, column: &str, value: T) -> Self {
         // For simple assignment, we use a trivial expression
-        self.updates.insert(column.to_string(), RayExpression {
+        let expr = RayExpression {
             operation: Operation::Eval,
             operands: vec![ExprOperand::Value(value.into())],
-        });
+        };
+        set_named(&mut self.updates, column, expr);
         self
     }
 
@@ -799,7 +1860,7 @@ pub type UpdateQuery = RayUpdateQuery;
 /// Insert query builder.
 pub struct RayInsertQuery {
     table: RayTable,
-    data: Option<RayObj>,
+    data: Option<Result<RayObj>>,
 }
 
 impl RayInsertQuery {
@@ -811,21 +1872,26 @@ impl RayInsertQuery {
     }
 
     /// Insert data as a dictionary of column -> values.
+    ///
+    /// Columns given as vectors of different lengths are rejected here, and
+    /// the error is reported by [`execute`](Self::execute).
     pub fn values<I, K, V>(mut self, data: I) -> Self
     where
         K: AsRef<str>,
         V: Into<RayObj>,
         I: IntoIterator<Item = (K, V)>,
     {
-        if let Ok(dict) = RayDict::from_pairs(data) {
-            self.data = Some(dict.ptr().clone());
-        }
+        self.data = Some(column_values(data));
         self
     }
 
-    /// Insert data from a RayList of row values.
+    /// Insert data from a RayList of values in column order.
+    ///
+    /// Values given as vectors of different lengths are rejected here, and
+    /// the error is reported by [`execute`](Self::execute).
     pub fn rows(mut self, data: RayList) -> Self {
-        self.data = Some(data.ptr().clone());
+        let columns = data.iter().enumerate().map(|(i, value)| (format!("value {}", i), value));
+        self.data = Some(check_column_lengths(columns).map(|()| data.ptr().clone()));
         self
     }
 
@@ -833,7 +1899,7 @@ impl RayInsertQuery {
     pub fn execute(self) -> Result<RayTable> {
         let data = self.data.ok_or_else(|| {
             RayforceError::QueryError("No data provided for insert".into())
-        })?;
+        })??;
 
         let table_ptr = ffi::quote(&self.table.ptr);
         
@@ -863,11 +1929,53 @@ impl RayInsertQuery {
 /// Type alias for backward compatibility.
 pub type InsertQuery = RayInsertQuery;
 
+/// Build the dict of column values for an insert or upsert, checking that
+/// the columns given as vectors agree on the number of rows.
+fn column_values<I, K, V>(data: I) -> Result<RayObj>
+where
+    K: AsRef<str>,
+    V: Into<RayObj>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let pairs: Vec<(String, RayObj)> = data
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.into()))
+        .collect();
+    check_column_lengths(pairs.iter().cloned())?;
+    RayDict::from_pairs(pairs).map(|dict| dict.ptr().clone())
+}
+
+/// Check that the vector values among `columns` have the same length.
+///
+/// Atoms and strings stand for a single row and are skipped.
+fn check_column_lengths(columns: impl Iterator<Item = (String, RayObj)>) -> Result<()> {
+    let mut first: Option<(String, i64)> = None;
+    for (name, value) in columns {
+        if value.type_code() < 0 || value.type_code() == TYPE_C8 as i8 {
+            continue;
+        }
+        match &first {
+            None => first = Some((name, value.len())),
+            Some((first_name, len)) if value.len() != *len => {
+                return Err(RayforceError::QueryError(format!(
+                    "column `{}` has {} values but `{}` has {}",
+                    name,
+                    value.len(),
+                    first_name,
+                    len
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
 /// Upsert query builder.
 pub struct RayUpsertQuery {
     table: RayTable,
     match_by_first: usize,
-    data: Option<RayObj>,
+    data: Option<Result<RayObj>>,
 }
 
 impl RayUpsertQuery {
@@ -886,9 +1994,7 @@ impl RayUpsertQuery {
         V: Into<RayObj>,
         I: IntoIterator<Item = (K, V)>,
     {
-        if let Ok(dict) = RayDict::from_pairs(data) {
-            self.data = Some(dict.ptr().clone());
-        }
+        self.data = Some(column_values(data));
         self
     }
 
@@ -896,10 +2002,21 @@ impl RayUpsertQuery {
     pub fn execute(self) -> Result<RayTable> {
         let data = self.data.ok_or_else(|| {
             RayforceError::QueryError("No data provided for upsert".into())
-        })?;
+        })??;
+
+        // The runtime's upsert takes the key count as an argument, so a
+        // concrete keyed table is passed flat and keyed again afterwards.
+        let key_columns = self.table.key_columns()?;
+        let rekey = !self.table.is_reference && !key_columns.is_empty();
+        let match_by_first = if key_columns.is_empty() {
+            self.match_by_first
+        } else {
+            key_columns.len()
+        };
+        let target = if rekey { self.table.flat()? } else { self.table.clone() };
 
-        let table_ptr = ffi::quote(&self.table.ptr);
-        let keys = RayObj::from(self.match_by_first as i64);
+        let table_ptr = ffi::quote(&target.ptr);
+        let keys = RayObj::from(match_by_first as i64);
         
         unsafe {
             let args = [table_ptr.as_ptr(), keys.as_ptr(), data.as_ptr()];
@@ -914,6 +2031,87 @@ impl RayUpsertQuery {
                 return Err(RayforceError::QueryError(msg));
             }
             
+            let table = if self.table.is_reference {
+                let sym = RaySymbol::from_ptr(RayObj::from_raw(result))?;
+                RayTable::from_name(&sym.value())
+            } else {
+                RayTable::from_ptr(RayObj::from_raw(result))?
+            };
+            if rekey {
+                let keys: Vec<&str> = key_columns.iter().map(String::as_str).collect();
+                return table.set_key(&keys);
+            }
+            Ok(table)
+        }
+    }
+}
+
+/// Type alias for backward compatibility.
+pub type UpsertQuery = RayUpsertQuery;
+
+/// Delete query builder.
+///
+/// Removes rows matching the WHERE conditions (all rows when there are none),
+/// or, in column mode, drops whole columns from the table.
+pub struct RayDeleteQuery {
+    table: RayTable,
+    columns: Vec<String>,
+    where_conditions: Vec<RayExpression>,
+}
+
+impl RayDeleteQuery {
+    fn new(table: RayTable) -> Self {
+        Self {
+            table,
+            columns: Vec::new(),
+            where_conditions: Vec::new(),
+        }
+    }
+
+    /// Delete the given columns instead of rows.
+    pub fn columns(mut self, cols: &[&str]) -> Self {
+        self.columns = cols.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Add a WHERE condition.
+    pub fn where_cond(mut self, expr: RayExpression) -> Self {
+        self.where_conditions.push(expr);
+        self
+    }
+
+    /// Execute the delete.
+    pub fn execute(self) -> Result<RayTable> {
+        if self.table.is_parted {
+            return Err(RayforceError::PartedTableError("delete".into()));
+        }
+        if !self.columns.is_empty() {
+            if !self.where_conditions.is_empty() {
+                return Err(RayforceError::QueryError(
+                    "Cannot combine column delete with a WHERE condition".into(),
+                ));
+            }
+            return self.delete_columns();
+        }
+
+        let query_dict = self.build_query_dict()?;
+        let mut args = RayList::new();
+        args.push(ffi::get_internal_function(Operation::Delete.name()).ok_or_else(|| {
+            RayforceError::CApiError("delete not found".into())
+        })?);
+        args.push(query_dict.ptr().clone());
+
+        unsafe {
+            let result = eval_obj(clone_obj(args.ptr().as_ptr()));
+            if result.is_null() {
+                return Err(RayforceError::QueryError("Delete query failed".into()));
+            }
+            if (*result).type_ == TYPE_ERR as i8 {
+                let msg = ffi::get_error_message(result);
+                drop_obj(result);
+                return Err(RayforceError::QueryError(msg));
+            }
+
             if self.table.is_reference {
                 let sym = RaySymbol::from_ptr(RayObj::from_raw(result))?;
                 Ok(RayTable::from_name(&sym.value()))
@@ -922,10 +2120,53 @@ impl RayUpsertQuery {
             }
         }
     }
+
+    fn delete_columns(&self) -> Result<RayTable> {
+        let existing = self.table.columns()?;
+        if let Some(missing) = self.columns.iter().find(|c| !existing.contains(c)) {
+            return Err(RayforceError::KeyNotFound(missing.clone()));
+        }
+
+        let mut kept = Vec::new();
+        for name in existing.iter().filter(|c| !self.columns.contains(c)) {
+            kept.push((name.as_str(), self.table.get_column(name)?));
+        }
+        let result = RayTable::from_dict(kept)?;
+
+        if self.table.is_reference {
+            let name = ffi::symbol_to_string(&self.table.ptr).ok_or(RayforceError::NullPointer)?;
+            result.save(&name)?;
+            Ok(RayTable::from_name(&name))
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn build_query_dict(&self) -> Result<RayDict> {
+        let mut pairs: Vec<(&str, RayObj)> = Vec::new();
+
+        // Add 'from'
+        if self.table.is_reference {
+            pairs.push(("from", ffi::quote(&self.table.ptr)));
+        } else {
+            pairs.push(("from", self.table.ptr.clone()));
+        }
+
+        // Add WHERE
+        if !self.where_conditions.is_empty() {
+            let mut combined = self.where_conditions[0].clone();
+            for cond in &self.where_conditions[1..] {
+                combined = combined.and(cond.clone());
+            }
+            pairs.push(("where", combined.compile()));
+        }
+
+        RayDict::from_pairs(pairs)
+    }
 }
 
 /// Type alias for backward compatibility.
-pub type UpsertQuery = RayUpsertQuery;
+pub type DeleteQuery = RayDeleteQuery;
 
 /// Type alias for backward compatibility.
 pub type Table = RayTable;
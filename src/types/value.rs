@@ -0,0 +1,121 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Runtime classification of objects whose type is not known statically.
+
+use crate::ffi::{self, RayObj};
+use crate::types::{RayDate, RayDict, RayGuid, RayList, RayTable, RayTime, RayTimestamp, RayType};
+use crate::*;
+
+/// A [`RayObj`] sorted by its runtime type, from [`RayObj::classify`].
+///
+/// Atoms are unpacked into Rust values; vectors and other containers keep
+/// their Rayforce representation.
+#[derive(Debug, Clone)]
+pub enum RayValue {
+    /// The null object.
+    Null,
+    /// A boolean atom.
+    Bool(bool),
+    /// A byte atom.
+    U8(u8),
+    /// An i16 atom.
+    I16(i16),
+    /// An i32 atom.
+    I32(i32),
+    /// An i64 atom.
+    Int(i64),
+    /// An f64 atom.
+    Float(f64),
+    /// A character atom.
+    Char(char),
+    /// A symbol atom.
+    Symbol(String),
+    /// A date atom.
+    Date(RayDate),
+    /// A time atom.
+    Time(RayTime),
+    /// A timestamp atom.
+    Timestamp(RayTimestamp),
+    /// A GUID atom.
+    Guid(uuid::Uuid),
+    /// A string (character vector).
+    String(String),
+    /// A typed vector of any other element type.
+    Vector(RayObj),
+    /// A generic list.
+    List(RayList),
+    /// A dictionary.
+    Dict(RayDict),
+    /// A table.
+    Table(RayTable),
+    /// A lambda or built-in function.
+    Function(RayObj),
+    /// An error object, with its message.
+    Error(String),
+    /// Anything else.
+    Other(RayObj),
+}
+
+impl RayValue {
+    /// Classify `obj` by its type code.
+    pub fn from_obj(obj: RayObj) -> Self {
+        if obj.is_nil() {
+            return RayValue::Null;
+        }
+        let code = obj.type_code();
+        let value = match code {
+            c if c == -(TYPE_B8 as i8) => bool::try_from(obj.clone()).map(RayValue::Bool),
+            c if c == -(TYPE_U8 as i8) => u8::try_from(obj.clone()).map(RayValue::U8),
+            c if c == -(TYPE_I16 as i8) => i16::try_from(obj.clone()).map(RayValue::I16),
+            c if c == -(TYPE_I32 as i8) => i32::try_from(obj.clone()).map(RayValue::I32),
+            c if c == -(TYPE_I64 as i8) => i64::try_from(obj.clone()).map(RayValue::Int),
+            c if c == -(TYPE_F64 as i8) => f64::try_from(obj.clone()).map(RayValue::Float),
+            c if c == -(TYPE_C8 as i8) => char::try_from(obj.clone()).map(RayValue::Char),
+            c if c == -(TYPE_SYMBOL as i8) => String::try_from(obj.clone()).map(RayValue::Symbol),
+            c if c == -(TYPE_DATE as i8) => RayDate::from_ptr(obj.clone()).map(RayValue::Date),
+            c if c == -(TYPE_TIME as i8) => RayTime::from_ptr(obj.clone()).map(RayValue::Time),
+            c if c == -(TYPE_TIMESTAMP as i8) => {
+                RayTimestamp::from_ptr(obj.clone()).map(RayValue::Timestamp)
+            }
+            c if c == -(TYPE_GUID as i8) => {
+                RayGuid::from_ptr(obj.clone()).map(|g| RayValue::Guid(g.to_uuid()))
+            }
+            c if c == TYPE_C8 as i8 => String::try_from(obj.clone()).map(RayValue::String),
+            c if c == TYPE_LIST as i8 => RayList::from_ptr(obj.clone()).map(RayValue::List),
+            _ if obj.is_vector() => Ok(RayValue::Vector(obj.clone())),
+            c if c == TYPE_TABLE as i8 => RayTable::from_ptr(obj.clone()).map(RayValue::Table),
+            c if c == TYPE_DICT as i8 => RayDict::from_ptr(obj.clone()).map(RayValue::Dict),
+            c if c == TYPE_LAMBDA as i8
+                || c == TYPE_UNARY as i8
+                || c == TYPE_BINARY as i8
+                || c == TYPE_VARY as i8 =>
+            {
+                Ok(RayValue::Function(obj.clone()))
+            }
+            c if c == TYPE_ERR as i8 => Ok(RayValue::Error(ffi::get_error_message(obj.as_ptr()))),
+            _ => Ok(RayValue::Other(obj.clone())),
+        };
+        value.unwrap_or(RayValue::Other(obj))
+    }
+}
@@ -0,0 +1,75 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Tests for Arrow RecordBatch conversion.
+
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use arrow::array::{Array, Float64Array, Int64Array, StringArray};
+use arrow::datatypes::DataType;
+use rayforce::{RayType, Symbol, Table, Vector};
+use serial_test::serial;
+
+fn trades() -> Table {
+    Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, i64::MIN, 3]).ptr().clone()),
+        ("price", Vector::<f64>::from_iter([10.5, 11.0, f64::NAN]).ptr().clone()),
+        ("sym", Vector::<Symbol>::from_iter(["AAPL", "MSFT", "GOOG"]).ptr().clone()),
+    ])
+    .unwrap()
+}
+
+#[test]
+#[serial]
+fn test_to_record_batch() {
+    init_runtime!();
+    let batch = trades().to_record_batch().unwrap();
+    let schema = batch.schema();
+    assert_eq!(schema.field(0).name(), "id");
+    assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+    assert_eq!(schema.field(1).data_type(), &DataType::Float64);
+    assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+    assert_eq!(batch.num_rows(), 3);
+
+    let ids = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(ids.value(0), 1);
+    assert!(ids.is_null(1));
+    let prices = batch.column(1).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(prices.value(1), 11.0);
+    assert!(prices.is_null(2));
+    let syms = batch.column(2).as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(syms.value(2), "GOOG");
+}
+
+#[test]
+#[serial]
+fn test_record_batch_roundtrip() {
+    init_runtime!();
+    let batch = trades().to_record_batch().unwrap();
+    let table = Table::from_record_batch(&batch).unwrap();
+    assert_eq!(table.columns().unwrap(), vec!["id", "price", "sym"]);
+    let ids = Vector::<i64>::from_ptr(table.get_column("id").unwrap()).unwrap();
+    assert_eq!(ids.as_slice(), &[1, i64::MIN, 3]);
+}
@@ -25,7 +25,10 @@
 
 mod common;
 
-use rayforce::RayObj;
+use chrono::{NaiveDate, NaiveTime};
+use rayforce::{
+    RayChar, RayDate, RayObj, RaySymbol, RayTable, RayTime, RayTimestamp, RayType, RayVector,
+};
 use serial_test::serial;
 
 #[test]
@@ -132,6 +135,28 @@ fn test_f64_slice_to_rayobj() {
     assert_eq!(obj.len(), 3);
 }
 
+#[test]
+#[serial]
+fn test_bool_slice_to_rayobj() {
+    init_runtime!();
+    let data = [true, false, true, true];
+    let obj: RayObj = data.as_slice().into();
+    assert_eq!(obj.type_code(), rayforce::TYPE_B8 as i8);
+    assert_eq!(obj.len(), 4);
+    assert_eq!(obj.as_slice::<bool>().unwrap(), &data);
+}
+
+#[test]
+#[serial]
+fn test_u8_slice_to_rayobj() {
+    init_runtime!();
+    let data = [0u8, 7, 128, 255];
+    let obj: RayObj = data.as_slice().into();
+    assert_eq!(obj.type_code(), rayforce::TYPE_U8 as i8);
+    assert_eq!(obj.len(), 4);
+    assert_eq!(obj.as_slice::<u8>().unwrap(), &data);
+}
+
 #[test]
 #[serial]
 fn test_rayobj_clone() {
@@ -215,3 +240,245 @@ fn test_empty_slice_to_rayobj() {
     assert!(!obj.is_nil());
     assert_eq!(obj.len(), 0);
 }
+
+#[test]
+#[serial]
+fn test_rayobj_to_i16() {
+    init_runtime!();
+    let v: i16 = RayObj::from(-7i16).try_into().unwrap();
+    assert_eq!(v, -7);
+    assert!(i16::try_from(RayObj::from(7i64)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_to_u8() {
+    init_runtime!();
+    let v: u8 = RayObj::from(200u8).try_into().unwrap();
+    assert_eq!(v, 200);
+    assert!(u8::try_from(RayObj::from(200i16)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_to_char() {
+    init_runtime!();
+    let v: char = RayChar::new('x').ptr().clone().try_into().unwrap();
+    assert_eq!(v, 'x');
+    assert!(char::try_from(RayObj::from("x")).is_err());
+}
+
+#[test]
+#[serial]
+fn test_symbol_rayobj_to_string() {
+    init_runtime!();
+    let v: String = RaySymbol::new("AAPL").ptr().clone().try_into().unwrap();
+    assert_eq!(v, "AAPL");
+    assert!(String::try_from(RayObj::from(1i64)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_to_naive_date() {
+    init_runtime!();
+    let date = NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+    let v: NaiveDate = RayDate::from_naive_date(date).ptr().clone().try_into().unwrap();
+    assert_eq!(v, date);
+    assert!(NaiveDate::try_from(RayObj::from(1i32)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_to_naive_time() {
+    init_runtime!();
+    let time = NaiveTime::from_hms_milli_opt(13, 45, 1, 500).unwrap();
+    let v: NaiveTime = RayTime::from_naive_time(time).ptr().clone().try_into().unwrap();
+    assert_eq!(v, time);
+    assert!(NaiveTime::try_from(RayObj::from(1i32)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_to_naive_datetime() {
+    init_runtime!();
+    let ts = NaiveDate::from_ymd_opt(2024, 1, 2)
+        .unwrap()
+        .and_hms_nano_opt(3, 4, 5, 6)
+        .unwrap();
+    let v: chrono::NaiveDateTime = RayTimestamp::from_naive_datetime(ts)
+        .ptr()
+        .clone()
+        .try_into()
+        .unwrap();
+    assert_eq!(v, ts);
+    assert!(chrono::NaiveDateTime::try_from(RayObj::from(1i64)).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_as_slice() {
+    init_runtime!();
+    let obj: RayObj = [1i64, 2, 3].as_slice().into();
+    assert_eq!(obj.as_slice::<i64>().unwrap(), &[1, 2, 3]);
+
+    let obj: RayObj = [1.5f64, 2.5].as_slice().into();
+    assert_eq!(obj.as_slice::<f64>().unwrap(), &[1.5, 2.5]);
+}
+
+#[test]
+#[serial]
+fn test_rayobj_as_slice_type_mismatch() {
+    init_runtime!();
+    let obj: RayObj = [1i64, 2, 3].as_slice().into();
+    assert!(obj.as_slice::<f64>().is_err());
+
+    let atom: RayObj = 1i64.into();
+    assert!(atom.as_slice::<i64>().is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_as_slice_empty() {
+    init_runtime!();
+    let data: [i64; 0] = [];
+    let obj: RayObj = data.as_slice().into();
+    assert!(obj.as_slice::<i64>().unwrap().is_empty());
+}
+
+#[test]
+#[serial]
+fn test_serialize_roundtrip_scalar() {
+    init_runtime!();
+    let bytes = RayObj::from(42i64).serialize().unwrap();
+    let back = RayObj::deserialize(&bytes).unwrap();
+    assert_eq!(i64::try_from(back).unwrap(), 42);
+}
+
+#[test]
+#[serial]
+fn test_serialize_roundtrip_vector() {
+    init_runtime!();
+    let obj: RayObj = [1.5f64, 2.5, 3.5].as_slice().into();
+    let back = RayObj::deserialize(&obj.serialize().unwrap()).unwrap();
+    assert_eq!(back.as_slice::<f64>().unwrap(), &[1.5, 2.5, 3.5]);
+}
+
+#[test]
+#[serial]
+fn test_serialize_roundtrip_table() {
+    init_runtime!();
+    let table = RayTable::from_dict([
+        ("id", RayVector::<i64>::from_iter([1i64, 2]).ptr().clone()),
+        ("sym", RayVector::<RaySymbol>::from_iter(["a", "b"]).ptr().clone()),
+    ])
+    .unwrap();
+    let bytes = table.as_ray_obj().serialize().unwrap();
+    let back = RayTable::from_ptr(RayObj::deserialize(&bytes).unwrap()).unwrap();
+    assert_eq!(back.columns().unwrap(), vec!["id", "sym"]);
+    assert_eq!(back.len().unwrap(), 2);
+}
+
+#[test]
+#[serial]
+fn test_deserialize_garbage() {
+    init_runtime!();
+    let bytes = RayObj::from(42i64).serialize().unwrap();
+    assert!(RayObj::deserialize(&bytes[..bytes.len() / 2]).is_err());
+    assert!(RayObj::deserialize(&[0xff; 3]).is_err());
+    assert!(RayObj::deserialize(&[]).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rayobj_scalar_ordering() {
+    init_runtime!();
+    let a = RayObj::from(1i64);
+    let b = RayObj::from(2i64);
+    assert!(a < b);
+    assert!(b > a);
+    assert_eq!(a.partial_cmp(&a.clone()), Some(std::cmp::Ordering::Equal));
+}
+
+#[test]
+#[serial]
+fn test_rayobj_vector_equality() {
+    init_runtime!();
+    let a: RayObj = [1i64, 2, 3].as_slice().into();
+    let b: RayObj = [1i64, 2, 3].as_slice().into();
+    let c: RayObj = [1i64, 2, 4].as_slice().into();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+#[serial]
+fn test_rayobj_cross_type_inequality() {
+    init_runtime!();
+    let a = RayObj::from(1i64);
+    let b = RayObj::from(1.0f64);
+    assert_ne!(a, b);
+    assert_eq!(a.partial_cmp(&b), None);
+}
+
+#[test]
+#[serial]
+fn test_rayobj_deep_equals_table() {
+    init_runtime!();
+    let make = |ids: [i64; 2]| {
+        RayTable::from_dict([("id", RayVector::<i64>::from_iter(ids).ptr().clone())]).unwrap()
+    };
+    let a = make([1, 2]);
+    assert!(a.as_ray_obj().deep_equals(make([1, 2]).as_ray_obj()).unwrap());
+    assert!(!a.as_ray_obj().deep_equals(make([1, 3]).as_ray_obj()).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_assert_unique() {
+    init_runtime!();
+    let obj = RayObj::from(1i64);
+    obj.assert_unique();
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "reference count 2")]
+fn test_assert_unique_shared() {
+    init_runtime!();
+    let obj: RayObj = [1i64, 2].as_slice().into();
+    let _shared = obj.clone();
+    obj.assert_unique();
+}
+
+#[test]
+#[serial]
+fn test_downcast_eval_result() {
+    with_runtime!(rf, {
+        let n: rayforce::RayI64 = rf.eval("(+ 40 2)").unwrap().downcast().unwrap();
+        assert_eq!(n.value(), 42);
+
+        let wrong = rf.eval("(+ 40 2)").unwrap().downcast::<rayforce::RayF64>();
+        assert!(matches!(wrong, Err(rayforce::RayforceError::TypeMismatch { .. })));
+    });
+}
+
+#[test]
+#[serial]
+fn test_classify_values() {
+    use rayforce::RayValue;
+
+    with_runtime!(rf, {
+        let table = rf.eval("(table [a b] (list [1 2] [3 4]))").unwrap();
+        match table.classify() {
+            RayValue::Table(t) => assert_eq!(t.len().unwrap(), 2),
+            other => panic!("expected a table, got {:?}", other),
+        }
+
+        assert!(matches!(rf.eval("42").unwrap().classify(), RayValue::Int(42)));
+        assert!(matches!(rf.eval("[1 2 3]").unwrap().classify(), RayValue::Vector(_)));
+        assert!(matches!(
+            rf.eval("'AAPL").unwrap().classify(),
+            RayValue::Symbol(s) if s == "AAPL"
+        ));
+    });
+}
@@ -25,7 +25,7 @@
 
 mod common;
 
-use rayforce::{Dict, I64, RayString, RayType};
+use rayforce::{Dict, I64, RayObj, RayString, RayType};
 use serial_test::serial;
 
 #[test]
@@ -152,3 +152,148 @@ fn test_dict_type_code() {
     // Just verify we can get the type code
     let _code = dict.type_code();
 }
+
+fn get_i64(dict: &Dict, key: &str) -> Option<i64> {
+    dict.get(key).map(|v| i64::try_from(v).unwrap())
+}
+
+#[test]
+#[serial]
+fn test_dict_insert_new_key() {
+    init_runtime!();
+    let mut dict = Dict::from_pairs([("a", 1i64)]).unwrap();
+    dict.insert("b", 2i64).unwrap();
+    assert_eq!(dict.len(), 2);
+    assert_eq!(get_i64(&dict, "b"), Some(2));
+}
+
+#[test]
+#[serial]
+fn test_dict_insert_overwrites() {
+    init_runtime!();
+    let mut dict = Dict::from_pairs([("a", 1i64)]).unwrap();
+    let shared = dict.clone();
+    dict.insert("a", 10i64).unwrap();
+    assert_eq!(dict.len(), 1);
+    assert_eq!(get_i64(&dict, "a"), Some(10));
+    assert_eq!(get_i64(&shared, "a"), Some(1));
+}
+
+#[test]
+#[serial]
+fn test_dict_remove() {
+    init_runtime!();
+    let mut dict = Dict::from_pairs([("a", 1i64), ("b", 2i64), ("c", 3i64)]).unwrap();
+    let removed = dict.remove("b").unwrap();
+    assert_eq!(i64::try_from(removed).unwrap(), 2);
+    assert_eq!(dict.len(), 2);
+    assert_eq!(get_i64(&dict, "b"), None);
+    assert_eq!(get_i64(&dict, "c"), Some(3));
+    assert!(dict.remove("missing").is_none());
+    assert_eq!(dict.len(), 2);
+}
+
+#[test]
+#[serial]
+fn test_dict_remove_leaves_clone_unchanged() {
+    init_runtime!();
+    let mut dict = Dict::from_pairs([("a", 1i64), ("b", 2i64)]).unwrap();
+    let shared = dict.clone();
+    dict.remove("a").unwrap();
+    assert_eq!(dict.len(), 1);
+    assert_eq!(get_i64(&dict, "b"), Some(2));
+    assert_eq!(shared.len(), 2);
+    assert_eq!(get_i64(&shared, "a"), Some(1));
+}
+
+#[test]
+#[serial]
+fn test_anymap_integer_and_string_keys() {
+    init_runtime!();
+    let mut map = rayforce::RayAnyMap::new();
+    map.insert(1i64, "one");
+    map.insert(2i64, "two");
+    map.insert("three", 3i64);
+    let shared = map.clone();
+    map.insert(1i64, "uno");
+
+    assert_eq!(map.len(), 3);
+    let one: String = map.get(&RayObj::from(1i64)).unwrap().try_into().unwrap();
+    assert_eq!(one, "uno");
+    let three: i64 = map.get(&RayObj::from("three")).unwrap().try_into().unwrap();
+    assert_eq!(three, 3);
+    let before: String = shared.get(&RayObj::from(1i64)).unwrap().try_into().unwrap();
+    assert_eq!(before, "one");
+}
+
+#[test]
+#[serial]
+fn test_anymap_missing_key_is_none() {
+    init_runtime!();
+    let mut map = rayforce::RayAnyMap::new();
+    assert!(map.is_empty());
+    map.insert(1i64, 10i64);
+    assert!(map.get(&RayObj::from(2i64)).is_none());
+    assert!(map.get(&RayObj::from("1")).is_none());
+    assert!(!map.contains_key(&RayObj::from("missing")));
+    assert!(map.contains_key(&RayObj::from(1i64)));
+}
+
+#[test]
+#[serial]
+fn test_dict_iter_pairs() {
+    init_runtime!();
+    let dict = Dict::from_pairs([
+        ("id", rayforce::RayObj::from(1i64)),
+        ("name", rayforce::RayObj::from("alice")),
+        ("score", rayforce::RayObj::from(9.5f64)),
+    ])
+    .unwrap();
+
+    let pairs: Vec<(String, rayforce::RayObj)> = dict.iter().collect();
+    let keys: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(keys, vec!["id", "name", "score"]);
+    assert_eq!(i64::try_from(pairs[0].1.clone()).unwrap(), 1);
+    assert_eq!(String::try_from(pairs[1].1.clone()).unwrap(), "alice");
+    assert_eq!(f64::try_from(pairs[2].1.clone()).unwrap(), 9.5);
+
+    let mut count = 0;
+    for (_key, _value) in &dict {
+        count += 1;
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+#[serial]
+fn test_dict_flip_to_table_and_back() {
+    init_runtime!();
+    let dict = Dict::from_pairs([
+        ("id", RayObj::from(vec![1i64, 2, 3])),
+        ("price", RayObj::from(vec![10.5f64, 11.0, 12.25])),
+    ])
+    .unwrap();
+
+    let table = dict.flip().unwrap();
+    assert_eq!(table.len().unwrap(), 3);
+    assert_eq!(table.columns().unwrap(), vec!["id", "price"]);
+
+    let back = table.flip().unwrap();
+    assert_eq!(back.len(), 2);
+    assert!(back.ptr().deep_equals(dict.ptr()).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_dict_flip_rejects_ragged_columns() {
+    init_runtime!();
+    let ragged = Dict::from_pairs([
+        ("a", RayObj::from(vec![1i64, 2])),
+        ("b", RayObj::from(vec![1i64, 2, 3])),
+    ])
+    .unwrap();
+    assert!(matches!(ragged.flip(), Err(rayforce::RayforceError::QueryError(_))));
+
+    let scalars = Dict::from_pairs([("a", I64::new(1).ptr().clone())]).unwrap();
+    assert!(scalars.flip().is_err());
+}
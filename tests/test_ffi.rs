@@ -157,3 +157,162 @@ fn test_list_operations() {
     ffi::push_to_list(&mut list, 2i64.into());
     assert_eq!(ffi::get_obj_len(&list), 2);
 }
+
+#[test]
+#[serial]
+fn test_format_debug_of_error_object() {
+    with_runtime!(rf, {
+        let parsed = rf.parse("(+ 1 \"a\")").unwrap();
+        let err = unsafe {
+            RayObj::from_raw(rayforce::eval_obj(rayforce::clone_obj(parsed.as_ptr())))
+        };
+        assert_eq!(err.type_code(), rayforce::TYPE_ERR as i8);
+
+        let message = match rf.eval_obj(&parsed) {
+            Err(rayforce::RayforceError::EvalFailed(msg)) => msg,
+            other => panic!("expected EvalFailed, got {:?}", other.map(|_| ())),
+        };
+        let debug = err.format_debug();
+        assert!(!debug.is_empty());
+        assert!(debug.contains(message.trim()));
+        assert_eq!(err.format_bytes(1), debug.as_bytes());
+    });
+}
+
+#[test]
+#[serial]
+fn test_format_compact_matches_display() {
+    init_runtime!();
+    let obj = RayObj::from(42i64);
+    assert_eq!(obj.format_compact(), obj.to_string());
+}
+
+#[test]
+#[serial]
+fn test_cast_i64_vector_to_f64() {
+    init_runtime!();
+    let ints = RayObj::from(vec![1i64, 2, 3]);
+    let floats = ints.to_f64_vector().unwrap();
+    assert_eq!(floats.type_code(), rayforce::TYPE_F64 as i8);
+    assert_eq!(floats.as_slice::<f64>().unwrap(), &[1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[serial]
+fn test_cast_string_to_symbol() {
+    init_runtime!();
+    let sym = RayObj::from("AAPL").cast(-(rayforce::TYPE_SYMBOL as i8)).unwrap();
+    assert_eq!(sym.type_code(), -(rayforce::TYPE_SYMBOL as i8));
+    assert_eq!(ffi::symbol_to_string(&sym).as_deref(), Some("AAPL"));
+}
+
+#[test]
+#[serial]
+fn test_invalid_cast_names_type_codes() {
+    init_runtime!();
+    let ints = RayObj::from(vec![1i64, 2, 3]);
+    match ints.cast(rayforce::TYPE_TABLE as i8) {
+        Err(rayforce::RayforceError::ConversionError(msg)) => {
+            let expected = format!("cast type {} to {}", rayforce::TYPE_I64, rayforce::TYPE_TABLE);
+            assert!(msg.contains(&expected));
+        }
+        other => panic!("expected ConversionError, got {:?}", other),
+    }
+}
+
+#[test]
+#[serial]
+fn test_peach_preserves_order() {
+    let rf = rayforce::Rayforce::builder()
+        .repl(false)
+        .worker_threads(2)
+        .build()
+        .unwrap();
+    let parts = rf.eval("(list [1 2] [3 4 5] [6] [7 8 9 10])").unwrap();
+    let sum = rf.eval("(fn [x] (sum x))").unwrap();
+    let totals = parts.peach(&sum).unwrap();
+    assert_eq!(totals.as_slice::<i64>().unwrap(), &[3, 12, 6, 34]);
+}
+
+#[test]
+#[serial]
+fn test_vector_list_and_enum_predicates() {
+    use rayforce::RayType;
+
+    with_runtime!(rf, {
+        let list = rf.eval("(list 1 \"a\" 'b)").unwrap();
+        assert!(list.is_list());
+        assert!(!list.is_vector());
+        assert!(!list.is_enum());
+
+        let ints = RayObj::from(vec![1i64, 2, 3]);
+        assert!(ints.is_vector());
+        assert!(!ints.is_list());
+        assert!(!ints.is_enum());
+
+        let domain = rayforce::RayVector::<rayforce::RaySymbol>::from_iter(["a", "b"]);
+        let values = rayforce::RayVector::<rayforce::RaySymbol>::from_iter(["b", "a", "b"]);
+        let e = rayforce::RayEnum::enumerate(&domain, &values).unwrap();
+        assert!(e.ptr().is_enum());
+        assert!(!e.ptr().is_vector());
+        assert!(!e.ptr().is_list());
+    });
+}
+
+#[test]
+#[serial]
+fn test_scalar_and_collection_classification() {
+    use rayforce::*;
+
+    init_runtime!();
+    let dict = RayDict::from_pairs([("a", RayObj::from(1i64))]).unwrap();
+    let table = RayTable::from_dict([("a", RayObj::from(vec![1i64, 2]))]).unwrap();
+    let cases: Vec<(&str, RayObj, bool, bool)> = vec![
+        ("bool", RayObj::from(true), true, false),
+        ("u8", RayObj::from(7u8), true, false),
+        ("char", RayChar::new('x').ptr().clone(), true, false),
+        ("i16", RayObj::from(1i16), true, false),
+        ("i32", RayObj::from(1i32), true, false),
+        ("i64", RayObj::from(1i64), true, false),
+        ("f64", RayObj::from(1.5), true, false),
+        ("symbol", ffi::new_symbol("sym"), true, false),
+        ("date", RayDate::from_days(0).ptr().clone(), true, false),
+        ("time", RayTime::from_ms(0).ptr().clone(), true, false),
+        ("timestamp", RayTimestamp::from_nanos(0).ptr().clone(), true, false),
+        ("guid", RayGuid::random().ptr().clone(), true, false),
+        ("string", RayObj::from("text"), false, true),
+        ("list", RayList::from_iter([1i64, 2]).ptr().clone(), false, true),
+        ("vector", RayObj::from(vec![1i64, 2]), false, true),
+        ("dict", dict.ptr().clone(), false, true),
+        ("table", table.ptr().clone(), false, true),
+    ];
+
+    for (name, obj, scalar, collection) in cases {
+        assert_eq!(obj.is_scalar(), scalar, "{} is_scalar", name);
+        assert_eq!(obj.is_atom(), scalar, "{} is_atom", name);
+        assert_eq!(obj.is_collection(), collection, "{} is_collection", name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_byte_size() {
+    use rayforce::{RayList, RayType};
+
+    init_runtime!();
+    let empty = RayObj::from(Vec::<i64>::new());
+    let header = empty.byte_size();
+    assert!(header > 0);
+
+    let values: Vec<i64> = (0..1000).collect();
+    let vector = RayObj::from(values);
+    assert_eq!(vector.byte_size(), 8 * 1000 + header);
+
+    let bytes = RayObj::from(vec![0u8; 10].as_slice());
+    assert_eq!(bytes.byte_size(), 10 + header);
+
+    // Shared items are counted once per reference.
+    let list = RayList::from_iter([vector.clone(), vector.clone()]);
+    assert!(list.ptr().byte_size() >= 2 * vector.byte_size());
+    assert!(RayObj::from(42i64).byte_size() > 0);
+}
@@ -157,3 +157,203 @@ fn test_list_collect() {
     let list: List = [1i64, 2, 3].into_iter().collect();
     assert_eq!(list.len(), 3);
 }
+
+#[test]
+#[serial]
+fn test_list_extend() {
+    init_runtime!();
+    let mut list = List::new();
+    list.push(0i64);
+    list.extend((1..=100).map(|i| i as i64));
+    assert_eq!(list.len(), 101);
+
+    let last: i64 = list.get(100).unwrap().try_into().unwrap();
+    assert_eq!(last, 100);
+}
+
+#[test]
+#[serial]
+fn test_list_extend_empty_is_noop() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64, 2]);
+    let before = list.ptr().as_ptr();
+    list.extend(std::iter::empty::<i64>());
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.ptr().as_ptr(), before);
+}
+
+#[test]
+#[serial]
+fn test_list_extend_leaves_clone_unchanged() {
+    init_runtime!();
+    let original = List::from_iter([1i64, 2]);
+    let mut copy = original.clone();
+    copy.extend([3i64, 4]);
+    assert_eq!(copy.len(), 4);
+    assert_eq!(original.len(), 2);
+}
+
+fn values(list: &List) -> Vec<i64> {
+    list.iter().map(|item| i64::try_from(item).unwrap()).collect()
+}
+
+#[test]
+#[serial]
+fn test_list_pop() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64, 2]);
+    assert_eq!(i64::try_from(list.pop().unwrap()).unwrap(), 2);
+    assert_eq!(values(&list), vec![1]);
+    assert!(list.pop().is_some());
+    assert!(list.pop().is_none());
+    assert!(list.is_empty());
+}
+
+#[test]
+#[serial]
+fn test_list_remove_middle() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64, 2, 3]);
+    assert_eq!(i64::try_from(list.remove(1).unwrap()).unwrap(), 2);
+    assert_eq!(values(&list), vec![1, 3]);
+    assert!(list.remove(5).is_none());
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+#[serial]
+fn test_list_remove_many() {
+    init_runtime!();
+    let mut list = List::from_iter([0i64, 1, 2, 3, 4, 5]);
+    list.remove_many(&[4, 1, 4, 99, 0]);
+    assert_eq!(values(&list), vec![2, 3, 5]);
+    list.remove_many(&[]);
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+#[serial]
+fn test_list_removal_leaves_clone_unchanged() {
+    init_runtime!();
+    let original = List::from_iter([0i64, 1, 2, 3, 4]);
+    let mut copy = original.clone();
+    assert_eq!(i64::try_from(copy.pop().unwrap()).unwrap(), 4);
+    assert_eq!(i64::try_from(copy.remove(0).unwrap()).unwrap(), 0);
+    copy.remove_many(&[0, 2]);
+    assert_eq!(values(&copy), vec![2]);
+    assert_eq!(values(&original), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[serial]
+fn test_list_insert_shifts_right() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64, 3]);
+    list.insert(1, 2i64);
+    assert_eq!(values(&list), vec![1, 2, 3]);
+    list.insert(0, 0i64);
+    assert_eq!(values(&list), vec![0, 1, 2, 3]);
+}
+
+#[test]
+#[serial]
+fn test_list_insert_leaves_clone_unchanged() {
+    init_runtime!();
+    let original = List::from_iter([1i64, 3]);
+    let mut copy = original.clone();
+    copy.insert(1, 2i64);
+    assert_eq!(values(&copy), vec![1, 2, 3]);
+    assert_eq!(values(&original), vec![1, 3]);
+}
+
+#[test]
+#[serial]
+fn test_list_insert_at_len_appends() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64]);
+    list.insert(1, 2i64);
+    assert_eq!(values(&list), vec![1, 2]);
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "insertion index")]
+fn test_list_insert_past_len_panics() {
+    init_runtime!();
+    let mut list = List::from_iter([1i64]);
+    list.insert(3, 2i64);
+}
+
+#[test]
+#[serial]
+fn test_list_find() {
+    init_runtime!();
+    let list = List::from_iter([1i64, 2, 1]);
+    assert_eq!(list.find(&rayforce::RayObj::from(2i64)), Some(1));
+    assert_eq!(list.find(&rayforce::RayObj::from(1i64)), Some(0));
+    assert_eq!(list.find(&rayforce::RayObj::from(9i64)), None);
+}
+
+#[test]
+#[serial]
+fn test_extend_builds_large_list_like_push() {
+    init_runtime!();
+    let mut extended = List::new();
+    extended.extend(0..100_000i64);
+    let mut plain = List::new();
+    for i in 0..100_000i64 {
+        plain.push(i);
+    }
+
+    assert_eq!(extended.len(), 100_000);
+    assert_eq!(plain.len(), 100_000);
+    for idx in [0usize, 1, 50_000, 99_999] {
+        let a: i64 = extended.get(idx).unwrap().try_into().unwrap();
+        let b: i64 = plain.get(idx).unwrap().try_into().unwrap();
+        assert_eq!(a, idx as i64);
+        assert_eq!(a, b);
+    }
+
+    let shared = extended.clone();
+    extended.push(-1i64);
+    assert_eq!(shared.len(), 100_000);
+    assert_eq!(extended.len(), 100_001);
+}
+
+#[test]
+#[serial]
+fn test_push_typed_vectors() {
+    init_runtime!();
+    let mut list = List::new();
+    list.push(rayforce::RayVector::<i64>::from_iter([1, 2, 3]));
+    list.push(rayforce::RayVector::<f64>::from_iter([1.5, 2.5]));
+    list.push(rayforce::RayVector::<rayforce::RaySymbol>::from_iter(["a", "b"]));
+
+    assert_eq!(list.len(), 3);
+    let codes: Vec<i8> = list.iter().map(|item| item.type_code()).collect();
+    assert_eq!(
+        codes,
+        vec![
+            rayforce::TYPE_I64 as i8,
+            rayforce::TYPE_F64 as i8,
+            rayforce::TYPE_SYMBOL as i8
+        ]
+    );
+}
+
+#[test]
+#[serial]
+fn test_list_distinct() {
+    init_runtime!();
+    let mut list = List::new();
+    list.push(1i64);
+    list.push("x");
+    list.push(1i64);
+    list.push(2.5f64);
+    list.push("x");
+
+    let unique = list.distinct().unwrap();
+    assert_eq!(unique.len(), 3);
+    assert_eq!(i64::try_from(unique.get(0).unwrap()).unwrap(), 1);
+    assert_eq!(f64::try_from(unique.get(2).unwrap()).unwrap(), 2.5);
+}
@@ -0,0 +1,135 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Tests for the built-in function wrappers in `ops`.
+
+mod common;
+
+use rayforce::ops;
+use rayforce::{RayList, RayObj, RayTable, RayType, RayVector};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_til() {
+    init_runtime!();
+    assert_eq!(ops::til(5).as_slice(), &[0, 1, 2, 3, 4]);
+    assert!(ops::til(0).is_empty());
+}
+
+#[test]
+#[serial]
+fn test_reverse() {
+    init_runtime!();
+    let reversed = ops::reverse(ops::til(5).ptr()).unwrap();
+    assert_eq!(reversed.as_slice::<i64>().unwrap(), &[4, 3, 2, 1, 0]);
+}
+
+#[test]
+#[serial]
+fn test_raze() {
+    init_runtime!();
+    let nested = RayList::from_iter([
+        RayObj::from(vec![1i64, 2]),
+        RayObj::from(vec![3i64]),
+        RayObj::from(vec![4i64, 5]),
+    ]);
+    let flat = ops::raze(&nested).unwrap();
+    assert_eq!(flat.as_slice::<i64>().unwrap(), &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[serial]
+fn test_enlist() {
+    init_runtime!();
+    let list = ops::enlist(42i64);
+    assert_eq!(list.len(), 1);
+    assert_eq!(i64::try_from(list.get(0).unwrap()).unwrap(), 42);
+
+    let nested = ops::enlist(RayVector::<i64>::from_iter([1i64, 2]));
+    assert_eq!(nested.get(0).unwrap().len(), 2);
+}
+
+#[test]
+#[serial]
+fn test_aggregations() {
+    init_runtime!();
+    let values = RayObj::from(vec![3i64, 1, 4, 1, 5, 9]);
+
+    let total = ops::sum(&values).unwrap();
+    assert_eq!(total.type_code(), -(rayforce::TYPE_I64 as i8));
+    assert_eq!(i64::try_from(total).unwrap(), 23);
+
+    let mean = f64::try_from(ops::avg(&values).unwrap()).unwrap();
+    assert!((mean - 23.0 / 6.0).abs() < 1e-9);
+
+    let median = f64::try_from(ops::med(&values).unwrap()).unwrap();
+    assert_eq!(median, 3.5);
+
+    assert_eq!(i64::try_from(ops::min(&values).unwrap()).unwrap(), 1);
+    assert_eq!(i64::try_from(ops::max(&values).unwrap()).unwrap(), 9);
+    assert_eq!(i64::try_from(ops::count(&values).unwrap()).unwrap(), 6);
+}
+
+#[test]
+#[serial]
+fn test_running_aggregations() {
+    init_runtime!();
+    let sums = ops::running_sum(&RayVector::<f64>::from_iter([1.0, 2.0, 3.0])).unwrap();
+    assert_eq!(sums.as_slice(), &[1.0, 3.0, 6.0]);
+
+    let maxs = ops::running_max(&RayVector::<f64>::from_iter([3.0, 1.0, 4.0, 1.0, 5.0])).unwrap();
+    assert_eq!(maxs.as_slice(), &[3.0, 3.0, 4.0, 4.0, 5.0]);
+
+    let mins = ops::running_min(&RayVector::<f64>::from_iter([3.0, 1.0, 4.0])).unwrap();
+    assert_eq!(mins.as_slice(), &[3.0, 1.0, 1.0]);
+
+    let avgs = ops::running_avg(&RayVector::<f64>::from_iter([2.0, 4.0, 6.0])).unwrap();
+    assert_eq!(avgs.as_slice(), &[2.0, 3.0, 4.0]);
+}
+
+#[test]
+#[serial]
+fn test_take_drop_sublist() {
+    init_runtime!();
+    let ten = ops::til(10);
+    let ten = ten.ptr();
+
+    assert_eq!(ten.take_n(3).unwrap().as_slice::<i64>().unwrap(), &[0, 1, 2]);
+    assert_eq!(ten.take_n(-3).unwrap().as_slice::<i64>().unwrap(), &[7, 8, 9]);
+    assert_eq!(ten.drop_n(5).unwrap().as_slice::<i64>().unwrap(), &[5, 6, 7, 8, 9]);
+    assert_eq!(ten.drop_n(-8).unwrap().as_slice::<i64>().unwrap(), &[0, 1]);
+    assert_eq!(ten.sublist(2, 3).unwrap().as_slice::<i64>().unwrap(), &[2, 3, 4]);
+    assert_eq!(ten.sublist(-3, 2).unwrap().as_slice::<i64>().unwrap(), &[7, 8]);
+    assert_eq!(ten.sublist(8, 5).unwrap().as_slice::<i64>().unwrap(), &[8, 9]);
+    assert!(ten.sublist(12, 2).unwrap().as_slice::<i64>().unwrap().is_empty());
+
+    let list = RayList::from_iter([1i64, 2, 3]);
+    assert_eq!(list.ptr().take_n(2).unwrap().len(), 2);
+
+    let table = RayTable::from_dict([("id", ops::til(5).ptr().clone())]).unwrap();
+    let rows = RayTable::from_ptr(table.ptr().sublist(0, 10).unwrap()).unwrap();
+    assert_eq!(rows.len().unwrap(), 5);
+    let rows = RayTable::from_ptr(table.ptr().sublist(3, 10).unwrap()).unwrap();
+    assert_eq!(rows.get_column("id").unwrap().as_slice::<i64>().unwrap(), &[3, 4]);
+}
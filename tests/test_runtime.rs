@@ -141,3 +141,216 @@ fn test_eval_multiple() {
         assert_eq!(v3, 3);
     });
 }
+
+#[test]
+#[serial]
+fn test_parse_without_eval() {
+    with_runtime!(rf, {
+        let parsed = rf.parse("(+ 1 2)").unwrap();
+        assert_eq!(parsed.type_code(), rayforce::TYPE_LIST as i8);
+
+        let result = rf.eval_obj(&parsed).unwrap();
+        let val: i64 = result.try_into().unwrap();
+        assert_eq!(val, 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_parse_syntax_error() {
+    with_runtime!(rf, {
+        let result = rf.parse("(+ 1 2");
+        assert!(matches!(result, Err(rayforce::RayforceError::ParseError(_))));
+    });
+}
+
+#[test]
+#[serial]
+fn test_eval_classifies_errors() {
+    with_runtime!(rf, {
+        let unbalanced = rf.eval("(+ 1 (* 2 3)");
+        assert!(matches!(unbalanced, Err(rayforce::RayforceError::ParseError(_))));
+
+        let type_error = rf.eval("(+ 1 \"a\")");
+        assert!(matches!(type_error, Err(rayforce::RayforceError::EvalFailed(_))));
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_eval_traps_errors() {
+    with_runtime!(rf, {
+        let result = rf.try_eval("(+ 1 \"a\")");
+        assert!(matches!(result, Err(rayforce::RayforceError::EvalFailed(_))));
+
+        // The runtime is still usable afterwards.
+        let val: i64 = rf.try_eval("(+ 1 2)").unwrap().try_into().unwrap();
+        assert_eq!(val, 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_runtime_recreate_after_drop() {
+    let rf = rayforce::Rayforce::new().unwrap();
+    drop(rf);
+
+    with_runtime!(rf, {
+        let val: i64 = rf.eval("7").unwrap().try_into().unwrap();
+        assert_eq!(val, 7);
+    });
+}
+
+#[test]
+#[serial]
+fn test_second_runtime_is_rejected() {
+    with_runtime!(rf, {
+        let second = rayforce::Rayforce::new();
+        assert!(matches!(second, Err(rayforce::RayforceError::AlreadyInitialized)));
+
+        // The live runtime is untouched.
+        let val: i64 = rf.eval("5").unwrap().try_into().unwrap();
+        assert_eq!(val, 5);
+    });
+}
+
+#[test]
+#[serial]
+fn test_runtime_create_drop_cycles() {
+    for _ in 0..3 {
+        let rf = rayforce::Rayforce::new().unwrap();
+        let val: i64 = rf.eval("(+ 1 1)").unwrap().try_into().unwrap();
+        assert_eq!(val, 2);
+        drop(rf);
+    }
+}
+
+#[test]
+#[serial]
+fn test_eval_all_reports_failing_statement() {
+    with_runtime!(rf, {
+        let script = "(set a 1)\n(set b 2)\n(+ a \"x\")\n(set c 3)";
+        match rf.eval_all(script) {
+            Err(rayforce::RayforceError::StatementFailed { index, statement, .. }) => {
+                assert_eq!(index, 2);
+                assert_eq!(statement, "(+ a \"x\")");
+            }
+            other => panic!("expected StatementFailed, got {:?}", other.map(|r| r.len())),
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_eval_all_returns_one_result_per_statement() {
+    with_runtime!(rf, {
+        let script = "; setup\n(set a 1) (set b 2)\n(+ a\n   b)\n\"semi ; colon\"\n";
+        let results = rf.eval_all(script).unwrap();
+        assert_eq!(results.len(), 4);
+        let sum: i64 = results[2].clone().try_into().unwrap();
+        assert_eq!(sum, 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_startup_script_defines_functions() {
+    let path = std::env::temp_dir().join(format!("rayforce-startup-{}.ray", std::process::id()));
+    std::fs::write(&path, "; helpers\n(set double (fn [x] (* x 2)))\n(set base 20)\n")
+        .unwrap();
+
+    let rf = rayforce::Rayforce::builder()
+        .repl(false)
+        .with_startup_script(&path)
+        .build();
+    std::fs::remove_file(&path).unwrap();
+
+    let rf = rf.unwrap();
+    let result: i64 = rf.eval("(double base)").unwrap().try_into().unwrap();
+    assert_eq!(result, 40);
+}
+
+#[test]
+#[serial]
+fn test_missing_startup_script_fails_before_init() {
+    let result = rayforce::Rayforce::builder()
+        .repl(false)
+        .with_startup_script("/nonexistent/startup.ray")
+        .build();
+    assert!(matches!(result, Err(rayforce::RayforceError::IoError(_))));
+
+    // No runtime was created, so a new one can start.
+    let rf = rayforce::Rayforce::new().unwrap();
+    drop(rf);
+}
+
+#[test]
+#[serial]
+fn test_builder_typed_options() {
+    let rf = rayforce::Rayforce::builder()
+        .repl(false)
+        .worker_threads(2)
+        .data_dir(std::env::temp_dir())
+        .build()
+        .unwrap();
+    assert!(!rf.as_ptr().is_null());
+    drop(rf);
+
+    let rf = rayforce::Rayforce::builder().repl(false).port(15_101).build().unwrap();
+    assert!(!rf.as_ptr().is_null());
+}
+
+#[test]
+#[serial]
+fn test_builder_rejects_conflicting_options() {
+    use rayforce::RayforceError;
+
+    let result = rayforce::Rayforce::builder()
+        .repl(false)
+        .port(15_102)
+        .with_arg("-p")
+        .with_arg("15103")
+        .build();
+    assert!(matches!(result, Err(RayforceError::InvalidConfig(_))));
+
+    let result = rayforce::Rayforce::builder().repl(false).worker_threads(0).build();
+    assert!(matches!(result, Err(RayforceError::InvalidConfig(_))));
+
+    let result = rayforce::Rayforce::builder()
+        .repl(false)
+        .data_dir("/nonexistent/rayforce-data")
+        .build();
+    assert!(matches!(result, Err(RayforceError::InvalidConfig(_))));
+
+    // Validation happens before the runtime is created.
+    let rf = rayforce::Rayforce::new().unwrap();
+    drop(rf);
+}
+
+#[test]
+#[serial]
+fn test_apply_lambda_and_builtins() {
+    with_runtime!(rf, {
+        let double = rf.eval("(fn [x] (* x 2))").unwrap();
+        let result: i64 = rf
+            .apply(&double, &[rayforce::RayObj::from(21i64)])
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(result, 42);
+
+        let add = rayforce::Operation::Add.to_ray_obj().unwrap();
+        let args = [rayforce::RayObj::from(1i64), rayforce::RayObj::from(2i64)];
+        let sum: i64 = rf.apply(&add, &args).unwrap().try_into().unwrap();
+        assert_eq!(sum, 3);
+
+        assert!(matches!(
+            rf.apply(&add, &args[..1]),
+            Err(rayforce::RayforceError::EvalFailed(_))
+        ));
+        assert!(matches!(
+            rf.apply(&rayforce::RayObj::from(1i64), &args),
+            Err(rayforce::RayforceError::TypeMismatch { .. })
+        ));
+    });
+}
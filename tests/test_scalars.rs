@@ -229,6 +229,26 @@ fn test_symbol_underscore() {
     assert_eq!(format!("{}", val), "`my_var");
 }
 
+#[test]
+#[serial]
+fn test_symbol_equality_is_interned() {
+    init_runtime!();
+    assert_eq!(Symbol::new("x"), Symbol::new("x"));
+    assert_ne!(Symbol::new("x"), Symbol::new("y"));
+}
+
+#[test]
+#[serial]
+fn test_symbol_hashmap_key() {
+    init_runtime!();
+    let mut state = std::collections::HashMap::new();
+    state.insert(Symbol::new("AAPL"), 1);
+    state.insert(Symbol::new("MSFT"), 2);
+    assert_eq!(state.get(&Symbol::new("AAPL")), Some(&1));
+    assert_eq!(state.get(&Symbol::new("MSFT")), Some(&2));
+    assert_eq!(state.get(&Symbol::new("GOOG")), None);
+}
+
 #[test]
 #[serial]
 fn test_type_codes() {
@@ -266,3 +286,148 @@ fn test_f64_value_roundtrip() {
     let val = F64::new(original);
     assert_eq!(val.type_code().abs(), F64::TYPE_CODE.abs());
 }
+
+#[test]
+#[serial]
+fn test_date_add_days() {
+    init_runtime!();
+    let start = chrono::NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+    let date = rayforce::RayDate::from_naive_date(start).add_days(30);
+    assert_eq!(date.to_naive_date(), start + chrono::Duration::days(30));
+    assert_eq!(date.add_days(-30).to_naive_date(), start);
+}
+
+#[test]
+#[serial]
+fn test_time_add_ms() {
+    init_runtime!();
+    let time = rayforce::RayTime::from_ms(1_000).add_ms(500);
+    assert_eq!(time.ms(), 1_500);
+}
+
+#[test]
+#[serial]
+fn test_timestamp_arithmetic() {
+    init_runtime!();
+    let start = rayforce::RayTimestamp::from_nanos(1_700_000_000_000_000_000);
+    let end = start.add_nanos(90 * 1_000_000_000);
+    assert_eq!(&end - &start, Some(chrono::Duration::seconds(90)));
+    assert_eq!(start - end, Some(chrono::Duration::seconds(-90)));
+}
+
+#[test]
+#[serial]
+fn test_temporal_arithmetic_keeps_nulls_null() {
+    init_runtime!();
+    assert!(rayforce::RayDate::null().add_days(-1).ptr().is_typed_null());
+    assert!(rayforce::RayTime::null().add_ms(1).ptr().is_typed_null());
+    assert!(rayforce::RayTimestamp::null().add_nanos(-1).ptr().is_typed_null());
+
+    let ts = rayforce::RayTimestamp::from_nanos(0);
+    assert_eq!(&ts - &rayforce::RayTimestamp::null(), None);
+    assert_eq!(&rayforce::RayTimestamp::null() - &ts, None);
+}
+
+#[test]
+#[serial]
+fn test_temporal_arithmetic_saturates() {
+    init_runtime!();
+    let last = rayforce::RayDate::from_days(i32::MAX).add_days(1);
+    assert_eq!(last.days(), i32::MAX);
+    let first = rayforce::RayDate::from_days(i32::MIN + 1).add_days(-1);
+    assert_eq!(first.days(), i32::MIN + 1);
+    assert!(!first.ptr().is_typed_null());
+
+    let late = rayforce::RayTimestamp::from_nanos(i64::MAX).add_nanos(1);
+    assert_eq!(late.nanos(), i64::MAX);
+    let early = rayforce::RayTimestamp::from_nanos(i64::MIN + 1);
+    assert_eq!(&late - &early, None);
+}
+
+#[test]
+#[serial]
+fn test_temporal_parse_round_trip() {
+    init_runtime!();
+    let date = rayforce::RayDate::parse("2024-03-15").unwrap();
+    assert_eq!(date.to_string(), "2024-03-15");
+
+    let time = rayforce::RayTime::parse("09:30:15.250").unwrap();
+    assert_eq!(time.ms(), (9 * 3600 + 30 * 60 + 15) * 1000 + 250);
+    assert_eq!(time.to_string(), "09:30:15.250");
+    assert_eq!(rayforce::RayTime::parse("09:30:15").unwrap().to_string(), "09:30:15");
+
+    let ts = rayforce::RayTimestamp::parse("2024-03-15T10:30:00.5+01:00").unwrap();
+    assert_eq!(ts.to_string(), "2024-03-15 09:30:00.500");
+}
+
+#[test]
+#[serial]
+fn test_temporal_parse_rejects_malformed_input() {
+    init_runtime!();
+    let err = rayforce::RayDate::parse("2024-13-01").unwrap_err();
+    assert!(err.to_string().contains("2024-13-01"));
+    assert!(rayforce::RayTime::parse("25:00").is_err());
+    assert!(rayforce::RayTimestamp::parse("2024-03-15 10:30:00").is_err());
+}
+
+#[test]
+#[serial]
+fn test_symbol_id_round_trip() {
+    init_runtime!();
+    let sym = Symbol::new("interned_by_id");
+    let id = sym.id();
+    assert_eq!(Symbol::new("interned_by_id").id(), id);
+
+    let rebuilt = Symbol::from_id(id).unwrap();
+    assert_eq!(rebuilt.value(), "interned_by_id");
+    assert_eq!(rebuilt, sym);
+}
+
+#[test]
+#[serial]
+fn test_typed_nulls() {
+    init_runtime!();
+    let null_float = rayforce::RayF64::null();
+    assert!(null_float.value().is_nan());
+    assert!(null_float.ptr().is_typed_null());
+
+    assert!(rayforce::RayI64::null().ptr().is_typed_null());
+    assert!(rayforce::RayDate::null().ptr().is_typed_null());
+    assert!(rayforce::RaySymbol::null().ptr().is_typed_null());
+
+    assert!(!rayforce::RayF64::new(1.5).ptr().is_typed_null());
+    assert!(!rayforce::RayI64::new(0).ptr().is_typed_null());
+    assert!(!rayforce::RayObj::from(vec![i64::MIN]).is_typed_null());
+}
+
+#[test]
+#[serial]
+fn test_f64_infinities() {
+    with_runtime!(rf, {
+        let inf = rayforce::RayF64::inf();
+        let neg_inf = rayforce::RayF64::neg_inf();
+        assert!(inf.is_inf() && neg_inf.is_inf());
+        assert!(!F64::new(1e308).is_inf());
+        assert!(!rayforce::RayF64::null().is_inf());
+
+        let gt = rayforce::Operation::GreaterThan.to_ray_obj().unwrap();
+        let above: bool = rf
+            .apply(&gt, &[inf.ptr().clone(), rayforce::RayObj::from(1e308)])
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(above);
+        let below: bool = rf
+            .apply(&gt, &[neg_inf.ptr().clone(), rayforce::RayObj::from(-1e308)])
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(!below);
+        assert_ne!(inf.to_string(), neg_inf.to_string());
+
+        let bytes = neg_inf.ptr().serialize().unwrap();
+        let back = F64::from_ptr(rayforce::RayObj::deserialize(&bytes).unwrap()).unwrap();
+        assert!(back.is_inf());
+        assert!(back.value() < 0.0);
+    });
+}
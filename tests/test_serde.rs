@@ -0,0 +1,121 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Tests for serde round-trips of scalar and vector types.
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use chrono::{NaiveDate, NaiveTime};
+use rayforce::{
+    RayBool, RayDate, RayF64, RayI64, RayString, RaySymbol, RayTime, RayTimestamp, RayVector,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_serde_i64() {
+    init_runtime!();
+    let json = serde_json::to_string(&RayI64::new(42)).unwrap();
+    assert_eq!(json, "42");
+    let back: RayI64 = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.value(), 42);
+}
+
+#[test]
+#[serial]
+fn test_serde_f64() {
+    init_runtime!();
+    let json = serde_json::to_string(&RayF64::new(1.5)).unwrap();
+    assert_eq!(json, "1.5");
+    let back: RayF64 = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.value(), 1.5);
+}
+
+#[test]
+#[serial]
+fn test_serde_bool() {
+    init_runtime!();
+    let json = serde_json::to_string(&RayBool::new(true)).unwrap();
+    assert_eq!(json, "true");
+    let back: RayBool = serde_json::from_str(&json).unwrap();
+    assert!(back.value());
+}
+
+#[test]
+#[serial]
+fn test_serde_symbol_and_string() {
+    init_runtime!();
+    let json = serde_json::to_string(&RaySymbol::new("AAPL")).unwrap();
+    assert_eq!(json, "\"AAPL\"");
+    let back: RaySymbol = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.value(), "AAPL");
+
+    let json = serde_json::to_string(&RayString::from("hello")).unwrap();
+    assert_eq!(json, "\"hello\"");
+    let back: RayString = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_string(), "hello");
+}
+
+#[test]
+#[serial]
+fn test_serde_temporal() {
+    init_runtime!();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let json = serde_json::to_string(&RayDate::from_naive_date(date)).unwrap();
+    assert_eq!(json, "\"2024-03-15\"");
+    let back: RayDate = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_naive_date(), date);
+
+    let time = NaiveTime::from_hms_milli_opt(9, 30, 15, 250).unwrap();
+    let json = serde_json::to_string(&RayTime::from_naive_time(time)).unwrap();
+    assert_eq!(json, "\"09:30:15.250\"");
+    let back: RayTime = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_naive_time(), time);
+
+    let ts = date.and_hms_nano_opt(9, 30, 15, 123_456_789).unwrap();
+    let json = serde_json::to_string(&RayTimestamp::from_naive_datetime(ts)).unwrap();
+    assert_eq!(json, "\"2024-03-15T09:30:15.123456789\"");
+    let back: RayTimestamp = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_naive_datetime(), ts);
+}
+
+#[test]
+#[serial]
+fn test_serde_invalid_date() {
+    init_runtime!();
+    let result: Result<RayDate, _> = serde_json::from_str("\"not-a-date\"");
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn test_serde_vectors() {
+    init_runtime!();
+    let v = RayVector::<i64>::from_iter([1i64, 2, 3]);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2,3]");
+
+    let v = RayVector::<f64>::from_iter([1.5, 2.5]);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,2.5]");
+}
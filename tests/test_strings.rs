@@ -157,3 +157,27 @@ fn test_string_type_code() {
     // String type should have the C8 type code
     assert_eq!(s.type_code(), RayString::TYPE_CODE);
 }
+
+#[test]
+#[serial]
+fn test_string_concat() {
+    init_runtime!();
+    let a = RayString::new("hello, ");
+    let b = RayString::new("world");
+    let joined = a.concat(&b).unwrap();
+    assert_eq!(joined.to_string(), "hello, world");
+    assert_eq!(joined.len(), 12);
+    assert_eq!(a.to_string(), "hello, ");
+}
+
+#[test]
+#[serial]
+fn test_string_push_str() {
+    init_runtime!();
+    let mut s = RayString::new("foo");
+    let shared = s.clone();
+    s.push_str("bar");
+    assert_eq!(s.as_bytes(), b"foobar");
+    assert_eq!(s.len(), 6);
+    assert_eq!(shared.to_string(), "foo");
+}
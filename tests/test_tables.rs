@@ -0,0 +1,819 @@
+/*
+*   Copyright (c) 2025 Anton Kundenko <singaraiona@gmail.com>
+*   All rights reserved.
+
+*   Permission is hereby granted, free of charge, to any person obtaining a copy
+*   of this software and associated documentation files (the "Software"), to deal
+*   in the Software without restriction, including without limitation the rights
+*   to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+*   copies of the Software, and to permit persons to whom the Software is
+*   furnished to do so, subject to the following conditions:
+
+*   The above copyright notice and this permission notice shall be included in all
+*   copies or substantial portions of the Software.
+
+*   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+*   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+*   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+*   AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+*   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+*   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+*   SOFTWARE.
+*/
+
+//! Tests for Table type and query builders.
+
+mod common;
+
+use rayforce::{Column, List, RayType, Symbol, Table, Vector};
+use serial_test::serial;
+
+fn employees() -> Table {
+    Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2, 3, 4]).ptr().clone()),
+        ("dept", Vector::<Symbol>::from_iter(["IT", "HR", "IT", "OPS"]).ptr().clone()),
+        ("salary", Vector::<f64>::from_iter([75.0, 65.0, 85.0, 55.0]).ptr().clone()),
+    ])
+    .unwrap()
+}
+
+#[test]
+#[serial]
+fn test_delete_where() {
+    init_runtime!();
+    let result = employees()
+        .delete()
+        .where_cond(Column::new("dept").eq(Symbol::new("IT").ptr().clone()))
+        .execute()
+        .unwrap();
+    assert_eq!(result.len().unwrap(), 2);
+}
+
+#[test]
+#[serial]
+fn test_delete_all_rows() {
+    init_runtime!();
+    let result = employees().delete().execute().unwrap();
+    assert_eq!(result.len().unwrap(), 0);
+    assert_eq!(result.columns().unwrap(), vec!["id", "dept", "salary"]);
+}
+
+#[test]
+#[serial]
+fn test_delete_columns() {
+    init_runtime!();
+    let result = employees().delete().columns(&["salary"]).execute().unwrap();
+    assert_eq!(result.columns().unwrap(), vec!["id", "dept"]);
+    assert_eq!(result.len().unwrap(), 4);
+}
+
+#[test]
+#[serial]
+fn test_delete_unknown_column() {
+    init_runtime!();
+    let result = employees().delete().columns(&["missing"]).execute();
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn test_asof_join() {
+    init_runtime!();
+    let trades = Table::from_dict([
+        ("sym", Vector::<Symbol>::from_iter(["AAPL", "AAPL", "MSFT"]).ptr().clone()),
+        ("time", Vector::<i64>::from_iter([10i64, 25, 30]).ptr().clone()),
+        ("price", Vector::<f64>::from_iter([150.0, 151.0, 300.0]).ptr().clone()),
+    ])
+    .unwrap();
+    let quotes = Table::from_dict([
+        ("sym", Vector::<Symbol>::from_iter(["AAPL", "MSFT", "AAPL", "AAPL"]).ptr().clone()),
+        ("time", Vector::<i64>::from_iter([5i64, 8, 20, 40]).ptr().clone()),
+        ("bid", Vector::<f64>::from_iter([149.5, 299.0, 150.5, 152.0]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let joined = trades.asof_join(&quotes, &["sym", "time"]).unwrap();
+    assert_eq!(joined.len().unwrap(), 3);
+
+    let bids = Vector::<f64>::from_ptr(joined.get_column("bid").unwrap()).unwrap();
+    assert_eq!(bids.as_slice(), &[149.5, 150.5, 299.0]);
+}
+
+#[test]
+#[serial]
+fn test_window_join_counts() {
+    init_runtime!();
+    let trades = Table::from_dict([
+        ("sym", Vector::<Symbol>::from_iter(["A", "A", "B"]).ptr().clone()),
+        ("time", Vector::<i64>::from_iter([10i64, 20, 10]).ptr().clone()),
+    ])
+    .unwrap();
+    let quotes = Table::from_dict([
+        ("sym", Vector::<Symbol>::from_iter(["A", "A", "A", "A", "B"]).ptr().clone()),
+        ("time", Vector::<i64>::from_iter([9i64, 11, 12, 21, 30]).ptr().clone()),
+        ("bid", Vector::<f64>::from_iter([1.0, 2.0, 3.0, 4.0, 5.0]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let joined = trades.window_join(&quotes, &["sym", "time"], (-1, 1)).unwrap();
+    assert_eq!(joined.len().unwrap(), 3);
+
+    // Windows are inclusive: [9, 11] for A@10, [19, 21] for A@20, [9, 11] for B@10.
+    let bids = List::from_ptr(joined.get_column("bid").unwrap()).unwrap();
+    let counts: Vec<i64> = bids.iter().map(|cell| cell.len()).collect();
+    assert_eq!(counts, vec![2, 1, 0]);
+}
+
+#[test]
+#[serial]
+fn test_select_order_by_limit() {
+    init_runtime!();
+    let result = employees()
+        .select()
+        .columns(&["id", "salary"])
+        .where_cond(Column::new("salary").gt(60.0f64))
+        .order_by_desc(&["salary"])
+        .limit(2)
+        .execute()
+        .unwrap();
+    assert_eq!(result.len().unwrap(), 2);
+    let ids = Vector::<i64>::from_ptr(result.get_column("id").unwrap()).unwrap();
+    assert_eq!(ids.as_slice(), &[3, 1]);
+}
+
+#[test]
+#[serial]
+fn test_select_unknown_column() {
+    init_runtime!();
+    let err = employees().select().columns(&["id", "bonus"]).execute().unwrap_err();
+    assert!(matches!(err, rayforce::RayforceError::KeyNotFound(ref name) if name == "bonus"));
+
+    let err = employees()
+        .select()
+        .column_expr("total", Column::new("salary").sum())
+        .group_by(&["team"])
+        .execute()
+        .unwrap_err();
+    assert!(matches!(err, rayforce::RayforceError::KeyNotFound(ref name) if name == "team"));
+}
+
+#[test]
+#[serial]
+fn test_select_duplicate_columns_deduped() {
+    init_runtime!();
+    let result = employees().select().columns(&["id", "id", "salary"]).execute().unwrap();
+    assert_eq!(result.columns().unwrap(), vec!["id", "salary"]);
+}
+
+#[test]
+#[serial]
+fn test_select_computed_columns_keep_insertion_order() {
+    init_runtime!();
+    let table = employees();
+    for _ in 0..10 {
+        let result = table
+            .select()
+            .column_expr("zeta", Column::new("salary").mul(2i64))
+            .column_expr("alpha", Column::new("id").add(1i64))
+            .column_expr("mid", Column::new("salary").sub(1i64))
+            .execute()
+            .unwrap();
+        assert_eq!(result.columns().unwrap(), vec!["zeta", "alpha", "mid"]);
+    }
+}
+
+#[test]
+#[serial]
+fn test_select_group_by_order_by_limit_clamps() {
+    init_runtime!();
+    let result = employees()
+        .select()
+        .column_expr("total", Column::new("salary").sum())
+        .where_cond(Column::new("salary").gt(50.0f64))
+        .group_by(&["dept"])
+        .order_by(&["total"])
+        .limit(10)
+        .execute()
+        .unwrap();
+    assert_eq!(result.len().unwrap(), 3);
+    let totals = Vector::<f64>::from_ptr(result.get_column("total").unwrap()).unwrap();
+    assert_eq!(totals.as_slice(), &[55.0, 65.0, 160.0]);
+}
+
+#[test]
+#[serial]
+fn test_select_having_filters_groups() {
+    init_runtime!();
+    let result = employees()
+        .select()
+        .column_expr("n", Column::new("id").count())
+        .group_by(&["dept"])
+        .having(Column::new("n").gt(1i64))
+        .execute()
+        .unwrap();
+    assert_eq!(result.len().unwrap(), 1);
+    let counts = Vector::<i64>::from_ptr(result.get_column("n").unwrap()).unwrap();
+    assert_eq!(counts.as_slice(), &[2]);
+}
+
+#[test]
+#[serial]
+fn test_select_does_not_leak_references() {
+    init_runtime!();
+    let table = employees();
+    let salary = table.get_column("salary").unwrap();
+    let before = salary.ref_count();
+
+    for _ in 0..100 {
+        let result = table
+            .select()
+            .columns(&["id", "salary"])
+            .where_cond(Column::new("salary").gt(60.0f64))
+            .execute()
+            .unwrap();
+        result.as_ray_obj().assert_unique();
+    }
+
+    assert_eq!(salary.ref_count(), before);
+}
+
+#[test]
+#[serial]
+fn test_insert_reports_ragged_values() {
+    init_runtime!();
+    let err = employees()
+        .insert()
+        .values([
+            ("id", Vector::<i64>::from_iter([5i64, 6]).ptr().clone()),
+            ("dept", Vector::<Symbol>::from_iter(["HR"]).ptr().clone()),
+            ("salary", Vector::<f64>::from_iter([1.0, 2.0]).ptr().clone()),
+        ])
+        .execute()
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("column `dept` has 1 values"), "{}", msg);
+    assert!(!msg.contains("No data provided"));
+
+    let rows = List::from_iter([
+        Vector::<i64>::from_iter([5i64, 6]).ptr().clone(),
+        Vector::<Symbol>::from_iter(["HR", "IT"]).ptr().clone(),
+        Vector::<f64>::from_iter([1.0]).ptr().clone(),
+    ]);
+    let err = employees().insert().rows(rows).execute().unwrap_err();
+    assert!(err.to_string().contains("column `value 2` has 1 values"), "{}", err);
+}
+
+#[test]
+#[serial]
+fn test_rows_as_tuples() {
+    init_runtime!();
+    let names = List::from_iter([
+        rayforce::RayObj::from("alice"),
+        rayforce::RayObj::from("bob"),
+        rayforce::RayObj::from("carol"),
+    ]);
+    let table = Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2, 3]).ptr().clone()),
+        ("score", Vector::<f64>::from_iter([1.5, 2.5, 3.5]).ptr().clone()),
+        ("name", names.ptr().clone()),
+    ])
+    .unwrap();
+
+    let rows: Vec<(i64, f64, String)> = table.rows().unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (1, 1.5, "alice".to_string()),
+            (2, 2.5, "bob".to_string()),
+            (3, 3.5, "carol".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[serial]
+fn test_rows_type_mismatch() {
+    init_runtime!();
+    let result = employees().rows::<(f64,)>();
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn test_iter_rows_yields_dicts() {
+    init_runtime!();
+    let table = Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2, 3]).ptr().clone()),
+        ("qty", Vector::<i64>::from_iter([10i64, 20, 30]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let rows: Vec<_> = table.iter_rows().unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(rows.len(), 3);
+    for (row, (id, qty)) in rows.iter().zip([(1i64, 10i64), (2, 20), (3, 30)]) {
+        let keys: Vec<String> = row.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["id", "qty"]);
+        assert_eq!(i64::try_from(row.get("id").unwrap()).unwrap(), id);
+        assert_eq!(i64::try_from(row.get("qty").unwrap()).unwrap(), qty);
+    }
+}
+
+fn csv_schema() -> [(&'static str, i8); 3] {
+    [
+        ("id", rayforce::TYPE_I64 as i8),
+        ("price", rayforce::TYPE_F64 as i8),
+        ("sym", rayforce::TYPE_SYMBOL as i8),
+    ]
+}
+
+#[test]
+#[serial]
+fn test_from_csv_reader() {
+    init_runtime!();
+    let csv = "id,price,sym\n1,10.5,AAPL\n2,,\"MS,FT\"\n3,7.25,GOOG\n";
+    let table = Table::from_csv_reader(csv.as_bytes(), &csv_schema()).unwrap();
+    assert_eq!(table.columns().unwrap(), vec!["id", "price", "sym"]);
+    assert_eq!(table.len().unwrap(), 3);
+
+    let prices = Vector::<f64>::from_ptr(table.get_column("price").unwrap()).unwrap();
+    assert!(prices.as_slice()[1].is_nan());
+    let syms = Vector::<Symbol>::from_ptr(table.get_column("sym").unwrap()).unwrap();
+    assert_eq!(syms.get(1).unwrap(), "MS,FT");
+}
+
+#[test]
+#[serial]
+fn test_from_csv_reader_without_header() {
+    init_runtime!();
+    let options = rayforce::CsvOptions {
+        header: false,
+        ..Default::default()
+    };
+    let csv = "1,10.5,AAPL\r\n2,11.5,MSFT\r\n";
+    let table = Table::from_csv_reader_with(csv.as_bytes(), &csv_schema(), &options).unwrap();
+    assert_eq!(table.len().unwrap(), 2);
+}
+
+#[test]
+#[serial]
+fn test_from_csv_reader_invalid_value() {
+    init_runtime!();
+    let csv = "id,price,sym\nx,1.0,A\n";
+    assert!(Table::from_csv_reader(csv.as_bytes(), &csv_schema()).is_err());
+}
+
+#[test]
+#[serial]
+fn test_to_csv_roundtrip() {
+    init_runtime!();
+    let csv = "id,price,sym\n1,10.5,AAPL\n2,,\"MS,FT\"\n";
+    let table = Table::from_csv_reader(csv.as_bytes(), &csv_schema()).unwrap();
+
+    let mut out = Vec::new();
+    table.to_csv_writer(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), csv);
+
+    let back = Table::from_csv_reader(out.as_slice(), &csv_schema()).unwrap();
+    assert_eq!(back.columns().unwrap(), table.columns().unwrap());
+    assert_eq!(back.len().unwrap(), 2);
+    let ids = Vector::<i64>::from_ptr(back.get_column("id").unwrap()).unwrap();
+    assert_eq!(ids.as_slice(), &[1, 2]);
+}
+
+#[test]
+#[serial]
+fn test_to_csv_roundtrip_temporal_nulls() {
+    init_runtime!();
+    let schema = [
+        ("day", rayforce::TYPE_DATE as i8),
+        ("at", rayforce::TYPE_TIME as i8),
+        ("ts", rayforce::TYPE_TIMESTAMP as i8),
+    ];
+    let csv = "day,at,ts\n2024-03-01,09:30:00.250,2024-03-01T09:30:00.500\n,,\n";
+    let table = Table::from_csv_reader(csv.as_bytes(), &schema).unwrap();
+    let day = rayforce::RayDate::from_ptr(table.get_cell("day", 1).unwrap()).unwrap();
+    assert_eq!(day.days(), i32::MIN);
+    let ts = rayforce::RayTimestamp::from_ptr(table.get_cell("ts", 1).unwrap()).unwrap();
+    assert_eq!(ts.nanos(), i64::MIN);
+
+    let mut out = Vec::new();
+    table.to_csv_writer(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), csv);
+
+    let back = Table::from_csv_reader(out.as_slice(), &schema).unwrap();
+    assert!(back.equals(&table).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_meta_reports_column_types() {
+    init_runtime!();
+    let table = Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2]).ptr().clone()),
+        ("price", Vector::<f64>::from_iter([1.5, 2.5]).ptr().clone()),
+        ("sym", Vector::<Symbol>::from_iter(["a", "b"]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let meta = table.meta().unwrap();
+    let described: Vec<_> = meta.iter().map(|c| (c.name.as_str(), c.type_code)).collect();
+    assert_eq!(
+        described,
+        [
+            ("id", rayforce::TYPE_I64 as i8),
+            ("price", rayforce::TYPE_F64 as i8),
+            ("sym", rayforce::TYPE_SYMBOL as i8),
+        ]
+    );
+    assert_eq!(meta[0].type_name, "RayI64");
+}
+
+#[test]
+#[serial]
+fn test_with_column_and_drop_column() {
+    init_runtime!();
+    let table = employees();
+    let bonus = Vector::<i64>::from_iter([1i64, 2, 3, 4]);
+
+    let wider = table.with_column("bonus", bonus.ptr().clone()).unwrap();
+    assert_eq!(wider.columns().unwrap(), vec!["id", "dept", "salary", "bonus"]);
+    assert_eq!(table.columns().unwrap().len(), 3);
+
+    let narrower = wider.drop_column("dept").unwrap();
+    assert_eq!(narrower.columns().unwrap(), vec!["id", "salary", "bonus"]);
+    assert_eq!(narrower.len().unwrap(), 4);
+    assert!(narrower.drop_column("dept").is_err());
+}
+
+#[test]
+#[serial]
+fn test_with_column_rejects_length_mismatch() {
+    init_runtime!();
+    let short = Vector::<i64>::from_iter([1i64, 2]);
+    assert!(employees().with_column("bonus", short.ptr().clone()).is_err());
+}
+
+#[test]
+#[serial]
+fn test_rename_columns() {
+    init_runtime!();
+    let renamed = employees().rename(&[("salary", "pay")]).unwrap();
+    assert_eq!(renamed.columns().unwrap(), vec!["id", "dept", "pay"]);
+
+    let pay = renamed.get_column("pay").unwrap();
+    assert_eq!(pay.as_slice::<f64>().unwrap(), &[75.0, 65.0, 85.0, 55.0]);
+    assert!(employees().rename(&[("missing", "x")]).is_err());
+}
+
+#[test]
+#[serial]
+fn test_keyed_upsert_replaces_matching_rows() {
+    init_runtime!();
+    let keyed = employees().set_key(&["id"]).unwrap();
+    assert!(keyed.is_keyed());
+    assert!(!employees().is_keyed());
+    assert_eq!(keyed.key_columns().unwrap(), vec!["id"]);
+    assert_eq!(keyed.columns().unwrap(), vec!["id", "dept", "salary"]);
+
+    let updated = keyed
+        .upsert(0)
+        .values([
+            ("id", Vector::<i64>::from_iter([2i64]).ptr().clone()),
+            ("dept", Vector::<Symbol>::from_iter(["HR"]).ptr().clone()),
+            ("salary", Vector::<f64>::from_iter([99.0]).ptr().clone()),
+        ])
+        .execute()
+        .unwrap();
+
+    assert_eq!(updated.len().unwrap(), 4);
+    assert!(updated.is_keyed());
+    let salary = updated.get_column("salary").unwrap();
+    assert_eq!(salary.as_slice::<f64>().unwrap(), &[75.0, 99.0, 85.0, 55.0]);
+}
+
+#[test]
+#[serial]
+fn test_keyed_table_survives_round_trip() {
+    init_runtime!();
+    let keyed = employees().set_key(&["id"]).unwrap();
+
+    let rebuilt = Table::from_ptr(keyed.ptr().clone()).unwrap();
+    assert!(rebuilt.is_keyed());
+    assert_eq!(rebuilt.key_columns().unwrap(), vec!["id"]);
+
+    keyed.save("keyed_staff").unwrap();
+    let named = Table::from_name("keyed_staff");
+    assert!(named.is_keyed());
+    assert_eq!(named.key_columns().unwrap(), vec!["id"]);
+    assert_eq!(named.len().unwrap(), 4);
+    assert!(employees().key_columns().unwrap().is_empty());
+}
+
+#[test]
+#[serial]
+fn test_set_key_rejects_duplicate_columns() {
+    init_runtime!();
+    let err = employees().set_key(&["id", "id"]).unwrap_err();
+    assert!(matches!(err, rayforce::RayforceError::QueryError(_)));
+    assert!(matches!(
+        employees().set_key(&["missing"]),
+        Err(rayforce::RayforceError::KeyNotFound(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn test_select_computed_arithmetic_column() {
+    init_runtime!();
+    let trades = Table::from_dict([
+        ("price", Vector::<f64>::from_iter([10.0, 20.0, 30.0]).ptr().clone()),
+        ("size", Vector::<f64>::from_iter([1.0, 2.0, 3.0]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let result = trades
+        .select()
+        .column_expr("notional", Column::new("price") * Column::new("size"))
+        .column_expr("half", Column::new("price").div(2.0))
+        .execute()
+        .unwrap();
+
+    let notional = result.get_column("notional").unwrap();
+    assert_eq!(notional.as_slice::<f64>().unwrap(), &[10.0, 40.0, 90.0]);
+    let half = result.get_column("half").unwrap();
+    assert_eq!(half.as_slice::<f64>().unwrap(), &[5.0, 10.0, 15.0]);
+}
+
+#[test]
+#[serial]
+fn test_compiled_expression_reused_across_tables() {
+    init_runtime!();
+    let high = Column::new("x").gt(5i64).into_compiled();
+
+    let first = Table::from_dict([("x", Vector::<i64>::from_iter([1i64, 7, 9]).ptr().clone())])
+        .unwrap();
+    let second = Table::from_dict([
+        ("x", Vector::<i64>::from_iter([6i64, 2, 8, 3]).ptr().clone()),
+        ("y", Vector::<i64>::from_iter([1i64, 2, 3, 4]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let a = first.select().where_cond(high.clone()).execute().unwrap();
+    let b = second.select().where_cond(high.clone()).execute().unwrap();
+    assert_eq!(a.len().unwrap(), 2);
+    assert_eq!(b.len().unwrap(), 2);
+
+    let expected = first.select().where_cond(Column::new("x").gt(5i64)).execute().unwrap();
+    assert_eq!(a.to_string(), expected.to_string());
+    assert_eq!(format!("{:?}", high), r#"GreaterThan(Column("x"), 5)"#);
+}
+
+#[test]
+#[serial]
+fn test_expression_debug_output() {
+    init_runtime!();
+    let expr = Column::new("x").gt(5i64).and(Column::new("y").lt(10i64));
+
+    assert_eq!(
+        format!("{:?}", expr),
+        r#"And(GreaterThan(Column("x"), 5), LessThan(Column("y"), 10))"#
+    );
+    assert_eq!(expr.to_query_string(), "(and (> x 5) (< y 10))");
+}
+
+#[test]
+#[serial]
+fn test_select_not_and_null_filters() {
+    init_runtime!();
+    let table = Table::from_dict([(
+        "x",
+        Vector::<i64>::from_iter([1i64, 7, i64::MIN, 3, 9]).ptr().clone(),
+    )])
+    .unwrap();
+
+    let small = table
+        .select()
+        .where_cond(Column::new("x").gt(5i64).not())
+        .execute()
+        .unwrap();
+    assert_eq!(small.len().unwrap(), 3);
+
+    let nulls = table.select().where_cond(Column::new("x").is_null()).execute().unwrap();
+    assert_eq!(nulls.len().unwrap(), 1);
+
+    let present = table.select().where_cond(Column::new("x").is_not_null()).execute().unwrap();
+    assert_eq!(present.len().unwrap(), 4);
+}
+
+#[test]
+#[serial]
+fn test_group_by_xbar_buckets() {
+    init_runtime!();
+    let trades = Table::from_dict([
+        ("time", Vector::<i64>::from_iter([0i64, 1, 4, 5, 9, 12, 14]).ptr().clone()),
+        ("qty", Vector::<i64>::from_iter([1i64, 1, 1, 1, 1, 1, 1]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let bars = trades
+        .select()
+        .column_expr("volume", Column::new("qty").sum())
+        .group_by_expr("bucket", Column::new("time").xbar(5))
+        .execute()
+        .unwrap();
+
+    // Buckets 0, 5 and 10.
+    assert_eq!(bars.len().unwrap(), 3);
+}
+
+#[test]
+#[serial]
+fn test_format_with_truncates_rows() {
+    init_runtime!();
+    let table = Table::from_dict([
+        ("id", Vector::<i64>::from_iter(0..100).ptr().clone()),
+        ("qty", Vector::<i64>::from_iter((0..100).map(|i| i * 10)).ptr().clone()),
+    ])
+    .unwrap();
+
+    let text = table.format_with(80, 10);
+    let lines: Vec<&str> = text.lines().collect();
+    // Header, rule, 10 data rows, footer.
+    assert_eq!(lines.len(), 13);
+    assert!(lines[0].starts_with("id"));
+    assert!(lines[1].starts_with("--"));
+    assert_eq!(lines[12], "... 90 more rows");
+}
+
+#[test]
+#[serial]
+fn test_format_with_drops_columns_past_width() {
+    init_runtime!();
+    let text = employees().format_with(8, 10);
+    assert!(text.lines().all(|line| line.chars().count() <= 8));
+    assert!(text.contains("more columns"));
+}
+
+#[test]
+#[serial]
+fn test_from_columns_allocates_typed_columns() {
+    init_runtime!();
+    let table = Table::from_columns(
+        &[("qty", rayforce::TYPE_I32 as i8), ("price", rayforce::TYPE_F64 as i8)],
+        3,
+    )
+    .unwrap();
+
+    assert_eq!(table.len().unwrap(), 3);
+    let meta = table.meta().unwrap();
+    let described: Vec<_> = meta.iter().map(|c| (c.name.as_str(), c.type_code)).collect();
+    assert_eq!(
+        described,
+        vec![("qty", rayforce::TYPE_I32 as i8), ("price", rayforce::TYPE_F64 as i8)]
+    );
+    assert_eq!(table.get_column("qty").unwrap().as_slice::<i32>().unwrap(), &[0, 0, 0]);
+
+    assert!(Table::from_columns(&[("t", rayforce::TYPE_TABLE as i8)], 3).is_err());
+}
+
+#[test]
+#[serial]
+fn test_get_and_set_cell() {
+    init_runtime!();
+    let original = employees();
+    let mut table = original.clone();
+
+    let salary: f64 = table.get_cell("salary", 1).unwrap().try_into().unwrap();
+    assert_eq!(salary, 65.0);
+
+    table.set_cell("salary", 1, 70.0).unwrap();
+    let salary: f64 = table.get_cell("salary", 1).unwrap().try_into().unwrap();
+    assert_eq!(salary, 70.0);
+
+    // The clone it was made from still sees the old value.
+    let salary: f64 = original.get_cell("salary", 1).unwrap().try_into().unwrap();
+    assert_eq!(salary, 65.0);
+
+    assert!(matches!(
+        table.get_cell("bonus", 0),
+        Err(rayforce::RayforceError::KeyNotFound(_))
+    ));
+    assert!(matches!(
+        table.set_cell("salary", 4, 1.0),
+        Err(rayforce::RayforceError::IndexOutOfBounds { .. })
+    ));
+}
+
+#[test]
+#[serial]
+fn test_set_cell_rejects_mismatched_type() {
+    init_runtime!();
+    let mut table = employees();
+    assert!(matches!(
+        table.set_cell("id", 0, 1.5),
+        Err(rayforce::RayforceError::TypeMismatch { .. })
+    ));
+    let id: i64 = table.get_cell("id", 0).unwrap().try_into().unwrap();
+    assert_eq!(id, 1);
+}
+
+#[test]
+#[serial]
+fn test_append_row_to_named_table() {
+    init_runtime!();
+    employees().save("staff").unwrap();
+    let staff = Table::from_name("staff");
+
+    for i in 0..1000 {
+        staff
+            .append_row(&[
+                rayforce::RayObj::from(100 + i as i64),
+                rayforce::ffi::new_symbol("NEW"),
+                rayforce::RayObj::from(50.0),
+            ])
+            .unwrap();
+    }
+    assert_eq!(staff.len().unwrap(), 1004);
+
+    assert!(matches!(
+        staff.append_row(&[rayforce::RayObj::from(1i64)]),
+        Err(rayforce::RayforceError::QueryError(_))
+    ));
+    assert!(matches!(
+        staff.append_row(&[
+            rayforce::RayObj::from(1.5),
+            rayforce::ffi::new_symbol("NEW"),
+            rayforce::RayObj::from(50.0),
+        ]),
+        Err(rayforce::RayforceError::QueryError(_))
+    ));
+    assert_eq!(staff.len().unwrap(), 1004);
+}
+
+#[test]
+#[serial]
+fn test_table_equals() {
+    init_runtime!();
+    assert!(employees().equals(&employees()).unwrap());
+
+    let mut changed = employees();
+    changed.set_cell("salary", 1, 66.0f64).unwrap();
+    assert!(!employees().equals(&changed).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_table_diff() {
+    init_runtime!();
+    let before = Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2, 3]).ptr().clone()),
+        ("qty", Vector::<i64>::from_iter([10i64, 20, 30]).ptr().clone()),
+    ])
+    .unwrap();
+    let after = Table::from_dict([
+        ("id", Vector::<i64>::from_iter([1i64, 2, 4]).ptr().clone()),
+        ("qty", Vector::<i64>::from_iter([10i64, 25, 40]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let delta = before.diff(&after).unwrap();
+    assert_eq!(delta.len().unwrap(), 4);
+    let ids = Vector::<i64>::from_ptr(delta.get_column("id").unwrap()).unwrap();
+    assert_eq!(ids.as_slice(), &[2, 3, 2, 4]);
+
+    assert!(before.diff(&before).unwrap().is_empty().unwrap());
+}
+
+#[test]
+#[serial]
+fn test_select_execute_grouped() {
+    init_runtime!();
+    let trades = Table::from_dict([
+        ("sym", Vector::<Symbol>::from_iter(["a", "b", "a", "c", "b", "a"]).ptr().clone()),
+        ("size", Vector::<i64>::from_iter([1i64, 2, 3, 4, 5, 6]).ptr().clone()),
+    ])
+    .unwrap();
+
+    let groups = trades
+        .select()
+        .column_expr("total", Column::new("size").sum())
+        .group_by(&["sym"])
+        .execute_grouped()
+        .unwrap();
+    assert_eq!(groups.len(), 3);
+
+    let mut sums = std::collections::HashMap::new();
+    for (key, agg) in groups {
+        assert_eq!(key.len(), 1);
+        assert_eq!(agg.len(), 1);
+        let sym = String::try_from(key.get("sym").unwrap()).unwrap();
+        let total = i64::try_from(agg.get("total").unwrap()).unwrap();
+        sums.insert(sym, total);
+    }
+    assert_eq!(sums["a"], 10);
+    assert_eq!(sums["b"], 7);
+    assert_eq!(sums["c"], 4);
+
+    let err = trades.select().columns(&["sym"]).execute_grouped().unwrap_err();
+    assert!(matches!(err, rayforce::RayforceError::QueryError(_)));
+}
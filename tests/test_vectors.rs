@@ -25,7 +25,7 @@
 
 mod common;
 
-use rayforce::{Symbol, Vector};
+use rayforce::{RayType, Symbol, Vector};
 use serial_test::serial;
 
 #[test]
@@ -184,6 +184,35 @@ fn test_symbol_vector_single() {
     assert_eq!(vec.get(0), Some("single".to_string()));
 }
 
+#[test]
+#[serial]
+fn test_symbol_vector_bulk_interning() {
+    init_runtime!();
+    let names: Vec<String> = (0..100_000).map(|i| format!("sym{}", i % 1000)).collect();
+
+    let vec = Vector::<Symbol>::from_iter(&names);
+
+    assert_eq!(vec.len(), 100_000);
+    assert_eq!(vec.get(0), Some("sym0".to_string()));
+    assert_eq!(vec.get(99_999), Some("sym999".to_string()));
+    assert_eq!(vec.position("sym42"), Some(42));
+}
+
+#[test]
+#[serial]
+fn test_symbol_vector_interior_nul_is_interned_whole() {
+    init_runtime!();
+    let vec = Vector::<Symbol>::from_iter(["a\0b", "a"]);
+    assert_eq!(vec.len(), 2);
+
+    let id = |idx| {
+        let item = rayforce::ffi::get_at_index(vec.ptr(), idx).unwrap();
+        Symbol::from_ptr(item).unwrap().id()
+    };
+    assert_eq!(id(0), Symbol::new("a\0b").id());
+    assert_ne!(id(0), id(1));
+}
+
 #[test]
 #[serial]
 fn test_symbol_vector_with_numbers() {
@@ -223,3 +252,427 @@ fn test_vector_debug() {
     assert!(debug.contains("Vector"));
     assert!(debug.contains("3")); // length
 }
+
+#[test]
+#[serial]
+fn test_i32_vector_as_slice() {
+    init_runtime!();
+    let vec = Vector::<i32>::from_iter([10i32, -20, 30]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.as_slice(), &[10, -20, 30]);
+    assert_eq!(vec.element_type_code(), rayforce::TYPE_I32 as i8);
+}
+
+#[test]
+#[serial]
+fn test_i32_vector_get_set() {
+    init_runtime!();
+    let mut vec = Vector::<i32>::from_slice(&[1, 2, 3]);
+    vec.set(2, i32::MAX);
+    assert_eq!(vec.get(2), Some(i32::MAX));
+    assert_eq!(vec.get(3), None);
+}
+
+#[test]
+#[serial]
+fn test_i16_vector_as_slice() {
+    init_runtime!();
+    let vec = Vector::<i16>::from_iter([7i16, -8, 9]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.as_slice(), &[7, -8, 9]);
+    assert_eq!(vec.element_type_code(), rayforce::TYPE_I16 as i8);
+}
+
+#[test]
+#[serial]
+fn test_i16_vector_get_set() {
+    init_runtime!();
+    let mut vec = Vector::<i16>::new(2);
+    vec.set(0, i16::MIN);
+    vec.set(1, 42);
+    assert_eq!(vec.as_slice(), &[i16::MIN, 42]);
+    assert_eq!(vec.get(2), None);
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_get_set() {
+    init_runtime!();
+    let mut vec = Vector::<f32>::from_slice(&[1.5, -2.25, 3.0]);
+    assert_eq!(vec.to_vec(), vec![1.5f32, -2.25, 3.0]);
+    vec.set(0, 0.5);
+    assert_eq!(vec.get(0), Some(0.5));
+    assert_eq!(vec.get(3), None);
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_round_trips_through_runtime() {
+    with_runtime!(rf, {
+        let data: Vec<f32> = (0..10_000).map(|i| i as f32 * 0.5).collect();
+        let obj = rayforce::RayObj::from(Vector::<f32>::from_slice(&data));
+        assert_eq!(obj.type_code(), rayforce::TYPE_F64 as i8);
+        rayforce::ffi::set_global("readings", &obj).unwrap();
+
+        let total = f64::try_from(rf.eval("(sum readings)").unwrap()).unwrap();
+        assert_eq!(total, data.iter().map(|&v| v as f64).sum::<f64>());
+
+        let doubled = Vector::<f64>::from_ptr(rf.eval("(* readings 2.0)").unwrap()).unwrap();
+        assert_eq!(doubled.get(3), Some(3.0));
+        assert_eq!(doubled.len(), 10_000);
+    });
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_slice_is_half_the_f64_size() {
+    init_runtime!();
+    let data: Vec<f32> = (0..10_000).map(|i| i as f32 * 0.25).collect();
+    let narrow = Vector::<f32>::from_slice(&data);
+    let wide = narrow.to_f64();
+
+    assert_eq!(narrow.as_slice(), data.as_slice());
+    assert_eq!(std::mem::size_of_val(narrow.as_slice()), 40_000);
+    assert_eq!(
+        std::mem::size_of_val(narrow.as_slice()) * 2,
+        std::mem::size_of_val(wide.as_slice())
+    );
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_set_updates_slice_and_column() {
+    init_runtime!();
+    let mut vec = Vector::<f32>::from_slice(&[1.0, 2.0]);
+    assert_eq!(vec.as_slice(), &[1.0f32, 2.0]);
+    vec.set(1, 4.5);
+    assert_eq!(vec.as_slice(), &[1.0f32, 4.5]);
+    assert_eq!(vec.to_f64().as_slice(), &[1.0f64, 4.5]);
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_from_ptr() {
+    init_runtime!();
+    let obj = rayforce::RayObj::from(&[0.5f64, 1.5][..]);
+    let vec = obj.downcast::<Vector<f32>>().unwrap();
+    assert_eq!(vec.as_slice(), &[0.5f32, 1.5]);
+    assert!(rayforce::RayObj::from(7i64).downcast::<Vector<f32>>().is_err());
+}
+
+#[test]
+#[serial]
+fn test_f32_vector_to_f64() {
+    init_runtime!();
+    let vec = Vector::<f32>::from_iter([1.5f32, 2.5]);
+    let wide = vec.to_f64();
+    assert_eq!(wide.as_slice(), &[1.5f64, 2.5]);
+}
+
+#[test]
+#[serial]
+fn test_i64_vector_index() {
+    init_runtime!();
+    let mut vec = Vector::<i64>::from_iter([1i64, 2, 3, 4]);
+    assert_eq!(vec[3], 4);
+    vec[3] = 9;
+    assert_eq!(vec[3], 9);
+    assert_eq!(vec.as_slice(), &[1, 2, 3, 9]);
+}
+
+#[test]
+#[serial]
+fn test_f64_vector_index() {
+    init_runtime!();
+    let mut vec = Vector::<f64>::from_iter([1.5, 2.5]);
+    assert!((vec[1] - 2.5).abs() < 1e-10);
+    vec[0] += 1.0;
+    assert!((vec[0] - 2.5).abs() < 1e-10);
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "the len is 3 but the index is 3")]
+fn test_i64_vector_index_out_of_bounds() {
+    init_runtime!();
+    let vec = Vector::<i64>::from_iter([1i64, 2, 3]);
+    let _ = vec[3];
+}
+
+#[test]
+#[serial]
+fn test_i64_vector_iter() {
+    init_runtime!();
+    let vec = Vector::<i64>::from_iter([5i64, 6, 7]);
+    let visited: Vec<i64> = vec.iter().copied().collect();
+    assert_eq!(visited, vec![5, 6, 7]);
+    assert_eq!(vec.iter().sum::<i64>(), 18);
+}
+
+#[test]
+#[serial]
+fn test_f64_vector_into_iter_ref() {
+    init_runtime!();
+    let vec = Vector::<f64>::from_iter([0.5, 1.5, 2.5]);
+    let mut visited = Vec::new();
+    for v in &vec {
+        visited.push(*v);
+    }
+    assert_eq!(visited, vec![0.5, 1.5, 2.5]);
+}
+
+#[test]
+#[serial]
+fn test_clone_is_shallow_copy_is_deep() {
+    init_runtime!();
+    let v = Vector::<i64>::from_iter([1i64, 2, 3]);
+    let shallow = v.ptr().clone();
+    let deep = v.ptr().copy();
+
+    assert_eq!(v.ptr().ref_count(), 2);
+    assert_eq!(deep.ref_count(), 1);
+    assert_eq!(deep.as_slice::<i64>().unwrap(), &[1, 2, 3]);
+    drop(shallow);
+}
+
+#[test]
+#[serial]
+fn test_set_after_clone_does_not_mutate_original() {
+    init_runtime!();
+    let original = Vector::<i64>::from_iter([1i64, 2, 3]);
+    let mut copy = Vector::<i64>::from_ptr(original.ptr().clone()).unwrap();
+
+    copy.set(0, 100);
+    copy[1] = 200;
+
+    assert_eq!(copy.as_slice(), &[100, 200, 3]);
+    assert_eq!(original.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+#[serial]
+fn test_i64_position() {
+    init_runtime!();
+    let v = Vector::<i64>::from_iter([5i64, 7, 9, 7]);
+    assert_eq!(v.position(9), Some(2));
+    assert_eq!(v.position(7), Some(1));
+    assert_eq!(v.position(42), None);
+}
+
+#[test]
+#[serial]
+fn test_symbol_position() {
+    init_runtime!();
+    let v = Vector::<Symbol>::from_iter(["a", "b", "a"]);
+    assert_eq!(v.position("b"), Some(1));
+    assert_eq!(v.position("a"), Some(0));
+    assert_eq!(v.position("z"), None);
+    assert_eq!(v.position("never_interned_position_probe"), None);
+}
+
+#[test]
+#[serial]
+fn test_guid_vector_round_trip() {
+    init_runtime!();
+    let ids: Vec<uuid::Uuid> = (0..5).map(|_| uuid::Uuid::new_v4()).collect();
+    let v = Vector::<rayforce::RayGuid>::from_iter(ids.iter().copied());
+
+    assert_eq!(v.len(), 5);
+    assert_eq!(v.type_code(), rayforce::TYPE_GUID as i8);
+    for (i, id) in ids.iter().enumerate() {
+        assert_eq!(v.get(i), Some(*id));
+    }
+    assert_eq!(v.get(5), None);
+}
+
+#[test]
+#[serial]
+fn test_enum_resolves_through_domain() {
+    init_runtime!();
+    let domain = Vector::<Symbol>::from_iter(["AAPL", "GOOG", "MSFT"]);
+    let values = Vector::<Symbol>::from_iter(["MSFT", "AAPL", "MSFT", "GOOG"]);
+    let e = rayforce::RayEnum::enumerate(&domain, &values).unwrap();
+
+    assert_eq!(e.len(), 4);
+    assert_eq!(e.indices().unwrap().as_slice(), &[2, 0, 2, 1]);
+    assert_eq!(e.domain().unwrap().get(2), Some("MSFT".to_string()));
+    assert!(rayforce::RayEnum::from_ptr(e.ptr().clone()).is_ok());
+    assert!(rayforce::RayEnum::from_ptr(values.ptr().clone()).is_err());
+    let resolved: Vec<_> = (0..e.len()).map(|i| e.get(i).unwrap()).collect();
+    assert_eq!(resolved, vec!["MSFT", "AAPL", "MSFT", "GOOG"]);
+    assert_eq!(e.get(4), None);
+
+    let missing = Vector::<Symbol>::from_iter(["IBM"]);
+    assert!(matches!(
+        rayforce::RayEnum::enumerate(&domain, &missing),
+        Err(rayforce::RayforceError::KeyNotFound(s)) if s == "IBM"
+    ));
+}
+
+#[test]
+#[serial]
+fn test_map_ray_squares() {
+    with_runtime!(rf, {
+        let v = Vector::<i64>::from_ptr(rf.eval("(til 5)").unwrap()).unwrap();
+        let square = rf.eval("(fn [x] (* x x))").unwrap();
+        let result = v.map_ray(&square).unwrap();
+        assert_eq!(result.as_slice::<i64>().unwrap(), &[0, 1, 4, 9, 16]);
+    });
+}
+
+#[test]
+#[serial]
+fn test_resize_and_truncate() {
+    init_runtime!();
+    let mut v = Vector::<i64>::from_iter([1, 2, 3]);
+    let shared = v.clone();
+
+    v.resize(5, -1);
+    assert_eq!(v.len(), 5);
+    assert_eq!(v.as_slice(), &[1, 2, 3, -1, -1]);
+    assert_eq!(shared.as_slice(), &[1, 2, 3]);
+
+    v.truncate(2);
+    assert_eq!(v.as_slice(), &[1, 2]);
+    v.truncate(10);
+    assert_eq!(v.len(), 2);
+
+    let mut f = Vector::<f64>::from_iter([0.5]);
+    f.resize(3, 2.5);
+    assert_eq!(f.as_slice(), &[0.5, 2.5, 2.5]);
+    f.resize(1, 0.0);
+    assert_eq!(f.as_slice(), &[0.5]);
+}
+
+#[test]
+#[serial]
+fn test_concat_and_slice() {
+    init_runtime!();
+    let a = Vector::<i64>::from_iter([1, 2, 3]);
+    let b = Vector::<i64>::from_iter([4, 5]);
+
+    let joined = a.concat(&b);
+    assert_eq!(joined.len(), 5);
+    assert_eq!(joined.as_slice(), &[1, 2, 3, 4, 5]);
+    assert_eq!(a.concat(&Vector::<i64>::new(0)).as_slice(), &[1, 2, 3]);
+
+    let mid = joined.slice(1..4);
+    assert_eq!(mid.as_slice(), &[2, 3, 4]);
+    assert!(joined.slice(2..2).is_empty());
+
+    let mut owned = joined.slice(0..2);
+    owned.set(0, 100);
+    assert_eq!(joined.get(0), Some(1));
+}
+
+#[test]
+#[serial]
+#[should_panic]
+fn test_slice_out_of_bounds_panics() {
+    init_runtime!();
+    let v = Vector::<i64>::from_iter([1, 2, 3]);
+    let _ = v.slice(2..5);
+}
+
+#[test]
+#[serial]
+fn test_slice_methods_through_deref() {
+    init_runtime!();
+    let v = Vector::<i64>::from_iter([1, 3, 5, 7, 9]);
+    assert_eq!(v.binary_search(&7), Ok(3));
+    assert_eq!(v.iter().copied().max(), Some(9));
+    let sums: Vec<i64> = v.windows(3).map(|w| w.iter().sum()).collect();
+    assert_eq!(sums, vec![9, 15, 21]);
+
+    let f = Vector::<f64>::from_iter([1.0, 2.0, 3.0]);
+    assert_eq!(f.iter().rev().copied().collect::<Vec<_>>(), vec![3.0, 2.0, 1.0]);
+}
+
+#[test]
+#[serial]
+fn test_deref_mut_copies_shared_vector() {
+    init_runtime!();
+    let mut v = Vector::<i64>::from_iter([3, 1, 2]);
+    let shared = v.clone();
+    v.sort_unstable();
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert_eq!(shared.as_slice(), &[3, 1, 2]);
+}
+
+#[test]
+#[serial]
+fn test_symbol_vector_from_impls() {
+    init_runtime!();
+    let names: &[&str] = &["AAPL", "MSFT"];
+    let from_strs = Vector::<Symbol>::from(names);
+    assert_eq!(from_strs.len(), 2);
+    assert_eq!(from_strs.get(0).as_deref(), Some("AAPL"));
+
+    let owned = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let from_slice = Vector::<Symbol>::from(owned.as_slice());
+    assert_eq!(from_slice.get(2).as_deref(), Some("z"));
+    let from_vec = Vector::<Symbol>::from(owned);
+    assert_eq!(from_vec.len(), 3);
+    assert_eq!(from_vec.position("y"), Some(1));
+
+    let mut list = rayforce::RayList::new();
+    list.push(from_vec);
+    assert_eq!(list.get(0).unwrap().type_code(), rayforce::TYPE_SYMBOL as i8);
+
+    let dict = rayforce::RayDict::from_pairs([("syms", from_strs)]).unwrap();
+    assert_eq!(dict.get("syms").unwrap().len(), 2);
+}
+
+#[test]
+#[serial]
+fn test_argsort_and_take() {
+    init_runtime!();
+    let keys = Vector::<i64>::from_iter([3i64, 1, 2]);
+    let order = keys.argsort(false);
+    assert_eq!(order.as_slice(), &[1, 2, 0]);
+    assert_eq!(keys.argsort(true).as_slice(), &[0, 2, 1]);
+
+    let names = Vector::<Symbol>::from_iter(["c", "a", "b"]);
+    let sorted = names.ptr().take(&order).unwrap();
+    let sorted = Vector::<Symbol>::from_ptr(sorted).unwrap();
+    assert_eq!(sorted.get(0), Some("a".to_string()));
+    assert_eq!(sorted.get(2), Some("c".to_string()));
+
+    let prices = Vector::<f64>::from_iter([2.5, f64::NAN, 1.0, 2.5]);
+    assert_eq!(prices.argsort(false).as_slice(), &[1, 2, 0, 3]);
+    assert_eq!(prices.argsort(true).as_slice(), &[0, 3, 2, 1]);
+}
+
+#[test]
+#[serial]
+fn test_set_operations() {
+    init_runtime!();
+    let a = Vector::<i64>::from_iter([1i64, 2]);
+    let b = Vector::<i64>::from_iter([2i64, 3]);
+    assert_eq!(a.union(&b).unwrap().as_slice(), &[1, 2, 3]);
+    assert_eq!(a.intersect(&b).unwrap().as_slice(), &[2]);
+    assert_eq!(a.difference(&b).unwrap().as_slice(), &[1]);
+
+    let x = Vector::<Symbol>::from_iter(["a", "b"]);
+    let y = Vector::<Symbol>::from_iter(["b", "c"]);
+    let both = x.union(&y).unwrap();
+    assert_eq!(both.len(), 3);
+    assert_eq!(both.get(2), Some("c".to_string()));
+    assert_eq!(x.difference(&y).unwrap().get(0), Some("a".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_distinct_keeps_first_occurrence_order() {
+    init_runtime!();
+    let ids = Vector::<i64>::from_iter([3i64, 1, 3, 2, 1]);
+    assert_eq!(ids.distinct().unwrap().as_slice(), &[3, 1, 2]);
+
+    let prices = Vector::<f64>::from_iter([2.5, 2.5, 1.0]);
+    assert_eq!(prices.distinct().unwrap().as_slice(), &[2.5, 1.0]);
+
+    let syms = Vector::<Symbol>::from_iter(["b", "a", "b"]).distinct().unwrap();
+    assert_eq!(syms.len(), 2);
+    assert_eq!(syms.get(0), Some("b".to_string()));
+    assert_eq!(syms.get(1), Some("a".to_string()));
+}